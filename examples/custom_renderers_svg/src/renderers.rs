@@ -26,7 +26,8 @@ where
             on:click=move |mouse_event| on_click(TableRowEvent {
                 key: key.clone(),
                 index,
-                mouse_event,
+                kind: TableEventKind::Click,
+                trigger: TableEventTrigger::Mouse(mouse_event),
             })
         >
             <line x1="5" y1="0" x2="150" y2="0" stroke-width="1px" stroke="black" opacity="0.1" />
@@ -84,7 +85,7 @@ where
             on:click=move |mouse_event| on_click(TableHeadEvent {
                 index,
                 column,
-                mouse_event,
+                trigger: TableEventTrigger::Mouse(mouse_event),
             })
             style=style
         >
@@ -1,11 +1,9 @@
 use crate::server::*;
-use async_trait::async_trait;
 use leptos::*;
 use leptos_meta::*;
 use leptos_struct_table::*;
 use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
-use std::ops::Range;
 
 // This generates the component BookTable
 #[derive(TableComponent, Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
@@ -22,44 +20,14 @@ pub struct Book {
     pub publisher: String,
 }
 
-#[derive(Clone, PartialEq, Debug)]
-pub struct BookDataProvider {
-    sorting: VecDeque<(BookColumnName, ColumnSort)>,
-}
-
-// All we need to load data from a rest api is to implement TableDataProvider and it's `get_rows` methods.
-#[async_trait(?Send)]
-impl TableDataProvider<Book> for BookDataProvider {
-    // BookColumnName is generated by deriving TableComponent. It's an enum with all the column names.
-    type ColumnName = BookColumnName;
-
-    // TODO : Error handling
-    async fn get_rows(&self, range: Range<usize>) -> Vec<Book> {
-        // this would be probably better with some caching for previously loaded data but for
-        // simplicity we just load the data every time
-
-        let res = match get_books(self.sorting.clone()).await {
-            Ok(books) => books,
-            Err(_) => {
-                return vec![];
-            }
-        };
-
-        get_vec_range_clamped(&res, range)
-    }
-
-    fn set_sorting(&mut self, sorting: &VecDeque<(Self::ColumnName, ColumnSort)>) {
-        self.sorting = sorting.clone();
-    }
-}
-
 #[component]
 pub fn App() -> impl IntoView {
     provide_meta_context();
 
-    let items = create_rw_signal(BookDataProvider {
-        sorting: VecDeque::from([(BookColumnName::Id, ColumnSort::Descending)]),
-    });
+    // The provider calls the server function `get_books` with the range and sorting of the table
+    let items = create_rw_signal(ServerFnDataProvider::new(get_books).with_sorting(
+        VecDeque::from([(BookColumnName::Id, ColumnSort::Descending)]),
+    ));
 
     // let current_sorting = create_rw_signal( vec![]);
 
@@ -67,7 +35,7 @@ pub fn App() -> impl IntoView {
         log!("Sorting: {:?}", items.get());
         items
             .get()
-            .sorting
+            .sorting()
             .iter()
             .map(|(name, sort)| (format!("{name:?}"), format!("{sort:?}")))
             .collect::<Vec<_>>()
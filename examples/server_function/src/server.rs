@@ -1,27 +1,18 @@
 use crate::app::*;
 use leptos::*;
 use leptos_struct_table::*;
-use std::collections::VecDeque;
+use std::cmp::Ordering;
 
 #[server(GetBooks, "/api")]
-pub async fn get_books(
-    sorting: VecDeque<(BookColumnName, ColumnSort)>,
-) -> Result<Vec<Book>, ServerFnError> {
-    log!("{sorting:?}");
-    if sorting.is_empty() {
-        return Ok(vec![]);
-    }
+pub async fn get_books(query: TableQuery<BookColumnName>) -> Result<Vec<Book>, ServerFnError> {
+    log!("{query:?}");
 
-    let mut books = get_table_data_json();
-
-    for (name, sort_type) in sorting.iter().rev() {
-        books.sort_by(|b1, b2| b1.get(*name).partial_cmp(&b2.get(*name)).unwrap());
-        if *sort_type == ColumnSort::Descending {
-            books.reverse();
-        }
-    }
-
-    Ok(books)
+    // sorting and paging happens here on the server so only the visible rows are sent to the client
+    Ok(query.apply(get_table_data_json(), |b1, b2, name| {
+        b1.get(name)
+            .partial_cmp(&b2.get(name))
+            .unwrap_or(Ordering::Equal)
+    }))
 }
 
 pub fn get_table_data_json() -> Vec<Book> {
@@ -0,0 +1,92 @@
+use std::cell::{Cell, RefCell};
+use std::fmt::{Debug, Formatter};
+use std::rc::Rc;
+
+/// Signals that a data load isn't needed anymore, for example because the user changed the
+/// sorting while the previous rows were still loading.
+///
+/// It is passed to [`TableDataProvider::try_get_rows_abortable`](crate::TableDataProvider::try_get_rows_abortable)
+/// so providers can cancel their requests. [`TableController::load_rows`](crate::TableController::load_rows)
+/// drops the results of aborted loads in any case.
+///
+/// In the browser you can get a [`web_sys::AbortSignal`] with [`AbortSignal::to_web_sys`] and hand it
+/// to `fetch` based clients like `reqwasm`:
+///
+/// ```ignore
+/// let response = Request::get(&url)
+///     .abort_signal(abort_signal.to_web_sys().as_ref())
+///     .send()
+///     .await;
+/// ```
+#[derive(Clone, Default)]
+pub struct AbortSignal {
+    inner: Rc<AbortSignalInner>,
+}
+
+#[derive(Default)]
+struct AbortSignalInner {
+    aborted: Cell<bool>,
+    on_abort: RefCell<Vec<Box<dyn FnOnce()>>>,
+}
+
+impl AbortSignal {
+    /// Creates a new signal that is not aborted.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if the load has been aborted.
+    pub fn is_aborted(&self) -> bool {
+        self.inner.aborted.get()
+    }
+
+    /// Registers a callback that is called once when the load is aborted.
+    /// If it already has been aborted the callback is called immediately.
+    pub fn on_abort(&self, callback: impl FnOnce() + 'static) {
+        if self.is_aborted() {
+            callback();
+        } else {
+            self.inner.on_abort.borrow_mut().push(Box::new(callback));
+        }
+    }
+
+    /// Aborts the load. Calls all the callbacks registered with [`on_abort`](AbortSignal::on_abort).
+    /// This is called by the [`TableController`](crate::TableController) when a load is superseded.
+    pub fn abort(&self) {
+        if self.inner.aborted.replace(true) {
+            return;
+        }
+
+        let callbacks = self.inner.on_abort.take();
+        for callback in callbacks {
+            callback();
+        }
+    }
+
+    /// Returns `true` if both signals belong to the same load.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.inner, &other.inner)
+    }
+
+    /// Creates a [`web_sys::AbortSignal`] that is aborted together with this signal.
+    /// Returns `None` if the browser doesn't support `AbortController`.
+    ///
+    /// This is only available when compiling for `wasm32`.
+    #[cfg(target_arch = "wasm32")]
+    pub fn to_web_sys(&self) -> Option<web_sys::AbortSignal> {
+        let controller = web_sys::AbortController::new().ok()?;
+        let signal = controller.signal();
+
+        self.on_abort(move || controller.abort());
+
+        Some(signal)
+    }
+}
+
+impl Debug for AbortSignal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AbortSignal")
+            .field("aborted", &self.is_aborted())
+            .finish()
+    }
+}
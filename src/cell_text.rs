@@ -0,0 +1,74 @@
+use std::str::FromStr;
+
+/// Parses a value of a field from text, for example when it is pasted into an editable cell.
+/// Use it to implement [`TableRow::set_cell_text`](crate::TableRow::set_cell_text).
+///
+/// `format_string` is the [format string](crate::TableColumn::format_string) of the column. It is used to parse
+/// dates and times.
+pub trait FromCellText: Sized {
+    /// Parses `text` into a value. The error is a message that can be displayed to the user.
+    fn from_cell_text(text: &str, format_string: Option<&str>) -> Result<Self, String>;
+}
+
+macro_rules! from_cell_text_from_str {
+    ($($ty:ty),*) => {
+        $(
+            impl FromCellText for $ty {
+                fn from_cell_text(text: &str, _: Option<&str>) -> Result<Self, String> {
+                    <$ty>::from_str(text.trim()).map_err(|err| err.to_string())
+                }
+            }
+        )*
+    };
+}
+
+from_cell_text_from_str!(
+    bool, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64, char
+);
+
+impl FromCellText for String {
+    fn from_cell_text(text: &str, _: Option<&str>) -> Result<Self, String> {
+        Ok(text.to_string())
+    }
+}
+
+/// Empty text becomes `None`.
+impl<T: FromCellText> FromCellText for Option<T> {
+    fn from_cell_text(text: &str, format_string: Option<&str>) -> Result<Self, String> {
+        if text.trim().is_empty() {
+            Ok(None)
+        } else {
+            T::from_cell_text(text, format_string).map(Some)
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+macro_rules! from_cell_text_chrono {
+    ($($ty:ty),*) => {
+        $(
+            /// Uses the format string if given, otherwise the ISO 8601 format.
+            impl FromCellText for $ty {
+                fn from_cell_text(text: &str, format_string: Option<&str>) -> Result<Self, String> {
+                    let text = text.trim();
+
+                    match format_string {
+                        Some(format_string) => <$ty>::parse_from_str(text, format_string),
+                        None => <$ty>::from_str(text),
+                    }
+                    .map_err(|err| err.to_string())
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "chrono")]
+from_cell_text_chrono!(chrono::NaiveDate, chrono::NaiveDateTime, chrono::NaiveTime);
+
+#[cfg(feature = "uuid")]
+impl FromCellText for crate::uuid::Uuid {
+    fn from_cell_text(text: &str, _: Option<&str>) -> Result<Self, String> {
+        Self::from_str(text.trim()).map_err(|err| err.to_string())
+    }
+}
@@ -0,0 +1,230 @@
+use crate::Collation;
+#[cfg(feature = "chrono")]
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use leptos::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+
+/// A dynamically typed cell value. Used for tables whose columns are only known at runtime.
+/// See [`DynamicTable`](crate::DynamicTable).
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub enum CellValue {
+    #[default]
+    Empty,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Text(String),
+    #[cfg(feature = "chrono")]
+    Date(NaiveDate),
+    #[cfg(feature = "chrono")]
+    DateTime(NaiveDateTime),
+    #[cfg(feature = "chrono")]
+    Time(NaiveTime),
+}
+
+/// The type a JSON value is converted to by [`CellValue::from_json`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum CellValueType {
+    /// Use the type of the JSON value. Strings become text.
+    #[default]
+    Auto,
+    Bool,
+    Int,
+    Float,
+    Text,
+    /// Parses strings like `2023-04-10`.
+    #[cfg(feature = "chrono")]
+    Date,
+    /// Parses strings like `2023-04-10T12:30:00`.
+    #[cfg(feature = "chrono")]
+    DateTime,
+    /// Parses strings like `12:30:00`.
+    #[cfg(feature = "chrono")]
+    Time,
+}
+
+impl CellValue {
+    /// Converts a JSON value into a cell value of the given type.
+    /// Values that can't be converted become [`CellValue::Empty`]. `null` always becomes [`CellValue::Empty`].
+    /// Arrays and objects become their JSON text.
+    pub fn from_json(value: &Value, value_type: CellValueType) -> Self {
+        if value.is_null() {
+            return CellValue::Empty;
+        }
+
+        let converted = match value_type {
+            CellValueType::Auto => Some(CellValue::from(value.clone())),
+            CellValueType::Bool => value.as_bool().map(CellValue::Bool),
+            CellValueType::Int => value
+                .as_i64()
+                .or_else(|| value.as_str().and_then(|text| text.trim().parse().ok()))
+                .map(CellValue::Int),
+            CellValueType::Float => value
+                .as_f64()
+                .or_else(|| value.as_str().and_then(|text| text.trim().parse().ok()))
+                .map(CellValue::Float),
+            CellValueType::Text => Some(CellValue::Text(match value {
+                Value::String(text) => text.clone(),
+                _ => value.to_string(),
+            })),
+            #[cfg(feature = "chrono")]
+            CellValueType::Date => value
+                .as_str()
+                .and_then(|text| text.parse().ok())
+                .map(CellValue::Date),
+            #[cfg(feature = "chrono")]
+            CellValueType::DateTime => value
+                .as_str()
+                .and_then(|text| text.parse().ok())
+                .map(CellValue::DateTime),
+            #[cfg(feature = "chrono")]
+            CellValueType::Time => value
+                .as_str()
+                .and_then(|text| text.parse().ok())
+                .map(CellValue::Time),
+        };
+
+        converted.unwrap_or_default()
+    }
+
+    /// Formats the value for display.
+    /// `precision` is the number of digits after the decimal point for numbers.
+    /// `format_string` is used for dates and times. See [`chrono::format::strftime`] for more information.
+    #[allow(unused_variables)]
+    pub fn format(&self, precision: Option<usize>, format_string: Option<&str>) -> String {
+        match (self, precision) {
+            (CellValue::Int(value), Some(precision)) => format!("{:.precision$}", *value as f64),
+            (CellValue::Float(value), Some(precision)) => format!("{value:.precision$}"),
+            #[cfg(feature = "chrono")]
+            (CellValue::Date(value), _) if format_string.is_some() => {
+                value.format(format_string.unwrap_or_default()).to_string()
+            }
+            #[cfg(feature = "chrono")]
+            (CellValue::DateTime(value), _) if format_string.is_some() => {
+                value.format(format_string.unwrap_or_default()).to_string()
+            }
+            #[cfg(feature = "chrono")]
+            (CellValue::Time(value), _) if format_string.is_some() => {
+                value.format(format_string.unwrap_or_default()).to_string()
+            }
+            _ => self.to_string(),
+        }
+    }
+
+    /// Compares two values for sorting. Empty values come first, numbers are compared numerically
+    /// even if one is an integer and the other a float. Values of different types are ordered by type.
+    pub fn compare(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (CellValue::Int(a), CellValue::Int(b)) => a.cmp(b),
+            (CellValue::Int(a), CellValue::Float(b)) => (*a as f64).total_cmp(b),
+            (CellValue::Float(a), CellValue::Int(b)) => a.total_cmp(&(*b as f64)),
+            (CellValue::Float(a), CellValue::Float(b)) => a.total_cmp(b),
+            (CellValue::Bool(a), CellValue::Bool(b)) => a.cmp(b),
+            (CellValue::Text(a), CellValue::Text(b)) => a.cmp(b),
+            #[cfg(feature = "chrono")]
+            (CellValue::Date(a), CellValue::Date(b)) => a.cmp(b),
+            #[cfg(feature = "chrono")]
+            (CellValue::DateTime(a), CellValue::DateTime(b)) => a.cmp(b),
+            #[cfg(feature = "chrono")]
+            (CellValue::Time(a), CellValue::Time(b)) => a.cmp(b),
+            _ => self.type_order().cmp(&other.type_order()),
+        }
+    }
+
+    /// Same as [`compare`](CellValue::compare) but compares texts according to `collation`.
+    pub fn compare_with(&self, other: &Self, collation: &Collation) -> Ordering {
+        match (self, other) {
+            (CellValue::Text(a), CellValue::Text(b)) => collation.compare(a, b),
+            _ => self.compare(other),
+        }
+    }
+
+    fn type_order(&self) -> u8 {
+        match self {
+            CellValue::Empty => 0,
+            CellValue::Bool(_) => 1,
+            CellValue::Int(_) | CellValue::Float(_) => 2,
+            #[cfg(feature = "chrono")]
+            CellValue::Date(_) => 3,
+            #[cfg(feature = "chrono")]
+            CellValue::DateTime(_) => 4,
+            #[cfg(feature = "chrono")]
+            CellValue::Time(_) => 5,
+            CellValue::Text(_) => 6,
+        }
+    }
+}
+
+impl Display for CellValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CellValue::Empty => Ok(()),
+            CellValue::Bool(value) => value.fmt(f),
+            CellValue::Int(value) => value.fmt(f),
+            CellValue::Float(value) => value.fmt(f),
+            CellValue::Text(value) => value.fmt(f),
+            #[cfg(feature = "chrono")]
+            CellValue::Date(value) => value.fmt(f),
+            #[cfg(feature = "chrono")]
+            CellValue::DateTime(value) => value.fmt(f),
+            #[cfg(feature = "chrono")]
+            CellValue::Time(value) => value.fmt(f),
+        }
+    }
+}
+
+impl IntoView for CellValue {
+    fn into_view(self) -> View {
+        self.to_string().into_view()
+    }
+}
+
+impl From<Value> for CellValue {
+    fn from(value: Value) -> Self {
+        match value {
+            Value::Null => CellValue::Empty,
+            Value::Bool(value) => CellValue::Bool(value),
+            Value::Number(number) => match number.as_i64() {
+                Some(value) => CellValue::Int(value),
+                None => number
+                    .as_f64()
+                    .map(CellValue::Float)
+                    .unwrap_or(CellValue::Empty),
+            },
+            Value::String(value) => CellValue::Text(value),
+            value => CellValue::Text(value.to_string()),
+        }
+    }
+}
+
+macro_rules! cell_value_from {
+    ($variant:ident, $($ty:ty),*) => {
+        $(
+            impl From<$ty> for CellValue {
+                fn from(value: $ty) -> Self {
+                    CellValue::$variant(value.into())
+                }
+            }
+        )*
+    };
+}
+
+cell_value_from!(Bool, bool);
+cell_value_from!(Int, i8, i16, i32, i64, u8, u16, u32);
+cell_value_from!(Float, f32, f64);
+cell_value_from!(Text, String, &str);
+#[cfg(feature = "chrono")]
+cell_value_from!(Date, NaiveDate);
+#[cfg(feature = "chrono")]
+cell_value_from!(DateTime, NaiveDateTime);
+#[cfg(feature = "chrono")]
+cell_value_from!(Time, NaiveTime);
+
+impl<T: Into<CellValue>> From<Option<T>> for CellValue {
+    fn from(value: Option<T>) -> Self {
+        value.map(Into::into).unwrap_or_default()
+    }
+}
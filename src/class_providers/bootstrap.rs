@@ -1,4 +1,4 @@
-use crate::TableClassesProvider;
+use crate::{DropPosition, TableClassesProvider};
 
 #[derive(Clone, Copy)]
 pub struct BootstrapClassesPreset;
@@ -17,4 +17,63 @@ impl TableClassesProvider for BootstrapClassesPreset {
 
         format!("{} {}", active, template_classes)
     }
+
+    fn cell_changed(&self) -> String {
+        "table-warning".to_string()
+    }
+
+    fn loading_row(&self, _: usize) -> String {
+        "placeholder-glow".to_string()
+    }
+
+    fn loading_cell_inner(&self) -> String {
+        "placeholder col-12".to_string()
+    }
+
+    fn empty_row(&self) -> String {
+        "text-center text-muted".to_string()
+    }
+
+    fn actions_head_cell(&self) -> String {
+        "text-end".to_string()
+    }
+
+    fn actions_cell(&self) -> String {
+        "text-end text-nowrap".to_string()
+    }
+
+    fn drag_handle(&self) -> String {
+        "text-muted user-select-none".to_string()
+    }
+
+    fn drag_handle_head(&self) -> String {
+        "user-select-none".to_string()
+    }
+
+    fn row_dragging(&self) -> String {
+        "opacity-50".to_string()
+    }
+
+    fn row_drop_indicator(&self, position: DropPosition) -> String {
+        match position {
+            DropPosition::Before => "border-top border-primary border-2".to_string(),
+            DropPosition::After => "border-bottom border-primary border-2".to_string(),
+        }
+    }
+
+    fn context_menu(&self) -> String {
+        "dropdown-menu show".to_string()
+    }
+
+    fn context_menu_item(&self, enabled: bool) -> String {
+        if enabled {
+            "dropdown-item".to_string()
+        } else {
+            "dropdown-item disabled".to_string()
+        }
+    }
+
+    fn context_menu_icon(&self) -> String {
+        "me-2".to_string()
+    }
 }
@@ -1,7 +1,7 @@
 mod bootstrap;
 mod tailwind;
 
-use crate::ColumnSort;
+use crate::{ColumnSort, DropPosition};
 pub use bootstrap::*;
 pub use tailwind::*;
 
@@ -49,4 +49,82 @@ pub trait TableClassesProvider: Clone {
     fn cell(&self, template_classes: &str) -> String {
         template_classes.to_string()
     }
+
+    /// Get the classes that are temporarily added to a body cell after its value changed.
+    /// Use it together with [`create_change_flash`](crate::create_change_flash) in a custom cell renderer.
+    /// These are appended to the classes returned by [`cell`](TableClassesProvider::cell).
+    fn cell_changed(&self) -> String {
+        "changed".to_string()
+    }
+
+    #[allow(unused_variables)]
+    /// Get the classes for the placeholder rows that are displayed while the data is loading.
+    /// The `row_index` parameter contains the index of the placeholder row. The first row has index 0.
+    fn loading_row(&self, row_index: usize) -> String {
+        "loading".to_string()
+    }
+
+    /// Get the classes for the placeholder element inside of the cells of a loading row.
+    fn loading_cell_inner(&self) -> String {
+        "".to_string()
+    }
+
+    /// Get the classes for the row that is displayed when there is no data.
+    fn empty_row(&self) -> String {
+        "empty".to_string()
+    }
+
+    /// Get the classes for the header cell of the actions column.
+    fn actions_head_cell(&self) -> String {
+        "actions".to_string()
+    }
+
+    /// Get the classes for the body cells of the actions column.
+    fn actions_cell(&self) -> String {
+        "actions".to_string()
+    }
+
+    /// Get the classes for the cell that contains the drag handle of a reorderable row.
+    fn drag_handle(&self) -> String {
+        "drag-handle".to_string()
+    }
+
+    /// Get the classes for the empty header cell above the drag handles.
+    fn drag_handle_head(&self) -> String {
+        "drag-handle".to_string()
+    }
+
+    /// Get the classes that are added to a row while it is dragged.
+    fn row_dragging(&self) -> String {
+        "dragging".to_string()
+    }
+
+    /// Get the classes that are added to the row under the pointer while another row is dragged over it.
+    /// The `position` parameter indicates whether the dragged row would be dropped before or after this row.
+    fn row_drop_indicator(&self, position: DropPosition) -> String {
+        match position {
+            DropPosition::Before => "drop-before".to_string(),
+            DropPosition::After => "drop-after".to_string(),
+        }
+    }
+
+    /// Get the classes for the row context menu.
+    fn context_menu(&self) -> String {
+        "context-menu".to_string()
+    }
+
+    /// Get the classes for the items of the row context menu.
+    /// The `enabled` parameter indicates whether the action can be applied to the rows.
+    fn context_menu_item(&self, enabled: bool) -> String {
+        if enabled {
+            "context-menu-item".to_string()
+        } else {
+            "context-menu-item disabled".to_string()
+        }
+    }
+
+    /// Get the classes for the icons of the items of the row context menu.
+    fn context_menu_icon(&self) -> String {
+        "context-menu-icon".to_string()
+    }
 }
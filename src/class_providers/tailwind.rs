@@ -1,4 +1,4 @@
-use crate::{ColumnSort, TableClassesProvider};
+use crate::{ColumnSort, DropPosition, TableClassesProvider};
 
 #[derive(Clone, Copy)]
 pub struct TailwindClassesPreset;
@@ -61,4 +61,74 @@ impl TableClassesProvider for TailwindClassesPreset {
     fn cell(&self, template_classes: &str) -> String {
         format!("{} {}", "px-5 py-2", template_classes)
     }
+
+    fn cell_changed(&self) -> String {
+        "bg-yellow-100 dark:bg-yellow-900 transition-colors".to_string()
+    }
+
+    fn loading_row(&self, row_index: usize) -> String {
+        let bg_color = if row_index % 2 == 0 {
+            "bg-white dark:bg-gray-900"
+        } else {
+            "bg-gray-50 dark:bg-gray-800"
+        };
+
+        format!("{} {}", "border-b dark:border-gray-700", bg_color)
+    }
+
+    fn loading_cell_inner(&self) -> String {
+        "block h-4 rounded bg-gray-200 dark:bg-gray-700 animate-pulse".to_string()
+    }
+
+    fn empty_row(&self) -> String {
+        "bg-white dark:bg-gray-900 text-center text-gray-400 dark:text-gray-500".to_string()
+    }
+
+    fn actions_head_cell(&self) -> String {
+        "px-5 py-2 text-right".to_string()
+    }
+
+    fn actions_cell(&self) -> String {
+        "px-5 py-2 text-right whitespace-nowrap".to_string()
+    }
+
+    fn drag_handle(&self) -> String {
+        "w-8 px-2 text-gray-400 cursor-grab select-none".to_string()
+    }
+
+    fn drag_handle_head(&self) -> String {
+        "w-8 px-2".to_string()
+    }
+
+    fn row_dragging(&self) -> String {
+        "opacity-50".to_string()
+    }
+
+    fn row_drop_indicator(&self, position: DropPosition) -> String {
+        match position {
+            DropPosition::Before => "border-t-2 border-t-sky-500".to_string(),
+            DropPosition::After => "border-b-2 border-b-sky-500".to_string(),
+        }
+    }
+
+    fn context_menu(&self) -> String {
+        "min-w-[10rem] py-1 text-sm bg-white rounded shadow-lg ring-1 ring-black/5 dark:bg-gray-800 dark:ring-white/10 focus:outline-none".to_string()
+    }
+
+    fn context_menu_item(&self, enabled: bool) -> String {
+        let state = if enabled {
+            "text-gray-700 hover:bg-gray-100 dark:text-gray-300 dark:hover:bg-gray-700"
+        } else {
+            "text-gray-400 cursor-not-allowed dark:text-gray-500"
+        };
+
+        format!(
+            "{} {}",
+            "flex w-full items-center px-4 py-2 text-left", state
+        )
+    }
+
+    fn context_menu_icon(&self) -> String {
+        "mr-2".to_string()
+    }
 }
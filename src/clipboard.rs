@@ -0,0 +1,410 @@
+use crate::{TableColumn, TableDataProvider, TableRow};
+use core::fmt::Debug;
+
+/// Writes `rows` as tab-separated values like spreadsheets expect them when pasting.
+/// The first line contains the column titles if `include_titles` is `true`.
+///
+/// The values are formatted like in the table. Tabs and line breaks inside of values are replaced by spaces.
+pub fn write_tsv<T: TableRow>(rows: &[T], include_titles: bool) -> String {
+    let columns = T::ColumnName::columns();
+    let mut tsv = String::new();
+
+    if include_titles {
+        push_tsv_line(&mut tsv, columns.iter().map(|column| column.title()));
+    }
+
+    for row in rows {
+        push_tsv_line(
+            &mut tsv,
+            columns.iter().map(|column| row.cell_text(*column)),
+        );
+    }
+
+    tsv
+}
+
+fn push_tsv_line(tsv: &mut String, fields: impl Iterator<Item = String>) {
+    let line = fields
+        .map(|field| field.replace(['\t', '\r', '\n'], " "))
+        .collect::<Vec<_>>()
+        .join("\t");
+
+    tsv.push_str(&line);
+    tsv.push('\n');
+}
+
+/// Writes `rows` as an HTML table fragment with the column titles in the header.
+/// Email clients and office applications keep the table structure when this is pasted.
+pub fn write_html_table<T: TableRow>(rows: &[T]) -> String {
+    let columns = T::ColumnName::columns();
+    let mut html = String::from("<table><thead><tr>");
+
+    for column in &columns {
+        html.push_str(&format!("<th>{}</th>", escape_html(&column.title())));
+    }
+    html.push_str("</tr></thead><tbody>");
+
+    for row in rows {
+        html.push_str("<tr>");
+        for column in &columns {
+            html.push_str(&format!(
+                "<td>{}</td>",
+                escape_html(&row.cell_text(*column))
+            ));
+        }
+        html.push_str("</tr>");
+    }
+    html.push_str("</tbody></table>");
+
+    html
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Puts `rows` on the clipboard as TSV and as HTML table during a `copy` event.
+/// Returns `false` and leaves the event alone if there are no rows, so the browser copies the text selection as usual.
+///
+/// Call it in an `on:copy` handler around the table, for example with the selected rows.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// # #[derive(Clone)] struct Book;
+/// # #[derive(Copy, Clone)] enum BookColumnName {}
+/// # impl TableColumn for BookColumnName { fn columns() -> Vec<Self> { vec![] } fn title(&self) -> String { unreachable!() } }
+/// # impl TableRow for Book { type ColumnName = BookColumnName; fn cell_text(&self, _: BookColumnName) -> String { unreachable!() } }
+/// # fn test(marked_books: RwSignal<Vec<Book>>) {
+/// view! {
+///     <div tabindex="0" on:copy=move |event| { copy_rows_to_clipboard(&event, &marked_books.get_untracked()); }>
+///         // ...
+///     </div>
+/// };
+/// # }
+/// ```
+pub fn copy_rows_to_clipboard<T: TableRow>(event: &web_sys::ClipboardEvent, rows: &[T]) -> bool {
+    if rows.is_empty() {
+        return false;
+    }
+
+    let Some(clipboard_data) = event.clipboard_data() else {
+        return false;
+    };
+
+    let copied = clipboard_data
+        .set_data("text/plain", &write_tsv(rows, true))
+        .and_then(|_| clipboard_data.set_data("text/html", &write_html_table(rows)))
+        .is_ok();
+
+    if copied {
+        event.prevent_default();
+    }
+
+    copied
+}
+
+/// Returns the plain text of a `paste` event.
+pub fn pasted_text(event: &web_sys::ClipboardEvent) -> Option<String> {
+    event
+        .clipboard_data()
+        .and_then(|clipboard_data| clipboard_data.get_data("text/plain").ok())
+}
+
+/// Splits tab-separated text as it is copied from spreadsheets into lines of values.
+/// Values in double quotes can contain tabs, line breaks and escaped quotes (`""`).
+pub fn parse_tsv(tsv: &str) -> Vec<Vec<String>> {
+    let mut lines = vec![];
+    let mut line = vec![];
+    let mut value = String::new();
+    let mut quoted = false;
+    let mut chars = tsv.chars().peekable();
+
+    while let Some(char) = chars.next() {
+        match char {
+            '"' if quoted => {
+                if chars.peek() == Some(&'"') {
+                    chars.next();
+                    value.push('"');
+                } else {
+                    quoted = false;
+                }
+            }
+            '"' if value.is_empty() => quoted = true,
+            '\t' if !quoted => line.push(std::mem::take(&mut value)),
+            '\r' if !quoted && chars.peek() == Some(&'\n') => {}
+            '\n' if !quoted => {
+                line.push(std::mem::take(&mut value));
+                lines.push(std::mem::take(&mut line));
+            }
+            _ => value.push(char),
+        }
+    }
+
+    if !value.is_empty() || !line.is_empty() {
+        line.push(value);
+        lines.push(line);
+    }
+
+    lines
+}
+
+/// A pasted value that couldn't be set. See [`paste_tsv`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct PasteError<C> {
+    /// The index of the row in the table.
+    pub row_index: usize,
+    /// The column of the cell.
+    pub column: C,
+    /// The pasted text.
+    pub text: String,
+    /// Why the text couldn't be set.
+    pub message: String,
+}
+
+impl<C: TableColumn> std::fmt::Display for PasteError<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Row {}, {}: can't use \"{}\": {}",
+            self.row_index + 1,
+            self.column.title(),
+            self.text,
+            self.message
+        )
+    }
+}
+
+/// The result of [`paste_tsv`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct PastedRows<T: TableRow> {
+    /// The changed rows together with their index in the table.
+    pub rows: Vec<(usize, T)>,
+    /// The values that couldn't be parsed or set.
+    pub errors: Vec<PasteError<T::ColumnName>>,
+}
+
+impl<T> PastedRows<T>
+where
+    T: TableRow + Debug + PartialEq,
+{
+    /// Saves the changed rows as one batch with [`TableDataProvider::update_rows`].
+    /// Nothing is saved if any value couldn't be parsed. In that case the errors are returned joined by line breaks.
+    pub async fn submit<P>(self, provider: &P) -> Result<(), String>
+    where
+        P: TableDataProvider<T> + ?Sized,
+    {
+        if !self.errors.is_empty() {
+            return Err(self
+                .errors
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join("\n"));
+        }
+
+        if self.rows.is_empty() {
+            return Ok(());
+        }
+
+        provider.update_rows(&self.rows).await
+    }
+}
+
+/// Fills the cells starting at the focused cell with the values of a pasted TSV block.
+///
+/// `rows` are the currently loaded rows starting with the row of the focused cell, which has the index `first_row_index`
+/// in the table. `start_column` is the column of the focused cell. Every value is parsed with [`TableRow::set_cell_text`]
+/// into the field type. Values that don't fit into `rows` or the columns right of `start_column` are ignored.
+/// If the first line contains the column titles, as it does when it was copied with [`copy_rows_to_clipboard`], it is skipped.
+/// A single pasted value is always used even if it happens to equal the column title.
+///
+/// Call this in an `on:paste` handler with the [`pasted_text`] and save the result with [`PastedRows::submit`].
+pub fn paste_tsv<T>(
+    tsv: &str,
+    rows: &[T],
+    first_row_index: usize,
+    start_column: T::ColumnName,
+) -> PastedRows<T>
+where
+    T: TableRow + Clone,
+    T::ColumnName: PartialEq,
+{
+    let columns = T::ColumnName::columns();
+    let columns = match columns.iter().position(|column| *column == start_column) {
+        Some(start) => &columns[start..],
+        None => &[],
+    };
+
+    let mut lines = parse_tsv(tsv);
+    let is_title_line = lines.first().is_some_and(|line| {
+        (line.len() > 1 || lines.len() > 1)
+            && line.len() <= columns.len()
+            && line
+                .iter()
+                .zip(columns)
+                .all(|(value, column)| *value == column.title())
+    });
+    if is_title_line {
+        lines.remove(0);
+    }
+
+    let mut pasted = PastedRows {
+        rows: vec![],
+        errors: vec![],
+    };
+
+    for (offset, (line, row)) in lines.iter().zip(rows).enumerate() {
+        let row_index = first_row_index + offset;
+        let mut row = row.clone();
+
+        for (text, column) in line.iter().zip(columns) {
+            if let Err(message) = row.set_cell_text(*column, text) {
+                pasted.errors.push(PasteError {
+                    row_index,
+                    column: *column,
+                    text: text.clone(),
+                    message,
+                });
+            }
+        }
+
+        pasted.rows.push((row_index, row));
+    }
+
+    pasted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    enum BookColumn {
+        Title,
+        Year,
+    }
+
+    impl TableColumn for BookColumn {
+        fn columns() -> Vec<Self> {
+            vec![BookColumn::Title, BookColumn::Year]
+        }
+
+        fn title(&self) -> String {
+            format!("{self:?}")
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Book {
+        title: String,
+        year: u16,
+    }
+
+    impl TableRow for Book {
+        type ColumnName = BookColumn;
+
+        fn cell_text(&self, column: BookColumn) -> String {
+            match column {
+                BookColumn::Title => self.title.clone(),
+                BookColumn::Year => self.year.to_string(),
+            }
+        }
+
+        fn set_cell_text(&mut self, column: BookColumn, text: &str) -> Result<(), String> {
+            match column {
+                BookColumn::Title => self.title = text.to_string(),
+                BookColumn::Year => self.year = crate::FromCellText::from_cell_text(text, None)?,
+            }
+
+            Ok(())
+        }
+    }
+
+    fn book(title: &str, year: u16) -> Book {
+        Book {
+            title: title.to_string(),
+            year,
+        }
+    }
+
+    fn books() -> Vec<Book> {
+        vec![book("Dune", 1965), book("Emma", 1815)]
+    }
+
+    #[test]
+    fn write_tsv_replaces_tabs_and_line_breaks() {
+        let rows = vec![book("Tab\there", 2000), book("Line\nbreak", 2001)];
+
+        assert_eq!(
+            write_tsv(&rows, true),
+            "Title\tYear\nTab here\t2000\nLine break\t2001\n"
+        );
+        assert_eq!(write_tsv(&rows[..1], false), "Tab here\t2000\n");
+    }
+
+    #[test]
+    fn write_html_table_escapes_values() {
+        assert_eq!(
+            write_html_table(&[book("<b>\"A&B\"</b>", 1)]),
+            "<table><thead><tr><th>Title</th><th>Year</th></tr></thead>\
+             <tbody><tr><td>&lt;b&gt;&quot;A&amp;B&quot;&lt;/b&gt;</td><td>1</td></tr></tbody></table>"
+        );
+    }
+
+    #[test]
+    fn parse_tsv_handles_quotes_and_line_endings() {
+        assert_eq!(
+            parse_tsv("a\t\"b\tc\"\"d\"\r\nx\t\n\"multi\nline\""),
+            vec![
+                vec!["a".to_string(), "b\tc\"d".to_string()],
+                vec!["x".to_string(), "".to_string()],
+                vec!["multi\nline".to_string()],
+            ]
+        );
+        assert!(parse_tsv("").is_empty());
+    }
+
+    #[test]
+    fn paste_tsv_fills_cells_from_the_start_column() {
+        let pasted = paste_tsv("1900\n1901\t ignored\n1902", &books(), 5, BookColumn::Year);
+
+        assert_eq!(
+            pasted.rows,
+            vec![(5, book("Dune", 1900)), (6, book("Emma", 1901))]
+        );
+        assert!(pasted.errors.is_empty());
+    }
+
+    #[test]
+    fn paste_tsv_skips_the_title_line() {
+        let pasted = paste_tsv("Title\tYear\nIt\t1986", &books(), 0, BookColumn::Title);
+
+        assert_eq!(pasted.rows, vec![(0, book("It", 1986))]);
+
+        let pasted = paste_tsv("Year\n1986", &books(), 0, BookColumn::Year);
+
+        assert_eq!(pasted.rows, vec![(0, book("Dune", 1986))]);
+    }
+
+    #[test]
+    fn paste_tsv_keeps_a_single_value_that_equals_the_title() {
+        let pasted = paste_tsv("Title", &books(), 0, BookColumn::Title);
+
+        assert_eq!(pasted.rows, vec![(0, book("Title", 1965))]);
+    }
+
+    #[test]
+    fn paste_tsv_reports_values_that_cant_be_parsed() {
+        let pasted = paste_tsv("It\tsoon", &books(), 3, BookColumn::Title);
+
+        assert_eq!(pasted.rows.len(), 1);
+        assert_eq!(pasted.errors.len(), 1);
+        assert_eq!(pasted.errors[0].row_index, 3);
+        assert_eq!(pasted.errors[0].column, BookColumn::Year);
+        assert_eq!(pasted.errors[0].text, "soon");
+    }
+}
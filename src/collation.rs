@@ -0,0 +1,295 @@
+use std::cmp::Ordering;
+
+/// How text values of a column are compared when sorting. Return it from [`TableColumn::collation`](crate::TableColumn::collation)
+/// to use it with [`compare_rows`](crate::compare_rows) or call [`compare`](Collation::compare) in your own comparison function.
+///
+/// The default compares strings by their bytes like `Ord` for `String` does.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct Collation {
+    /// Ignores the case of letters. `"apple"` and `"Apple"` are equal.
+    pub case_insensitive: bool,
+    /// Compares sequences of digits by their numeric value. `"Item 2"` comes before `"Item 10"`.
+    pub natural: bool,
+    /// Sorts letters with diacritics like `"Ä"` next to their base letter instead of after `"Z"`.
+    ///
+    /// Only when compiled for `wasm32` the locale is respected. Then the browser's `Intl.Collator` is used with the
+    /// given locale like `"de-DE"` or `"sv"`. An empty string uses the default locale of the browser.
+    /// Everywhere else, for example on the server, the locale is ignored and common Latin letters with diacritics
+    /// are sorted as their base letter. Locales with their own rules are not tailored, so in Swedish `"Å"` is
+    /// sorted like `"A"` instead of after `"Z"`.
+    pub locale: Option<&'static str>,
+}
+
+impl Collation {
+    /// Compares two strings according to this collation.
+    pub fn compare(&self, a: &str, b: &str) -> Ordering {
+        #[cfg(target_arch = "wasm32")]
+        if let Some(locale) = self.locale {
+            return self.compare_intl(locale, a, b);
+        }
+
+        let ordering = if self.natural {
+            compare_natural(a, b, |a, b| self.compare_primary(a, b))
+        } else {
+            self.compare_primary(a, b)
+        };
+
+        ordering
+            .then_with(|| {
+                if self.locale.is_some() {
+                    a.to_lowercase().cmp(&b.to_lowercase())
+                } else {
+                    Ordering::Equal
+                }
+            })
+            .then_with(|| {
+                if self.case_insensitive {
+                    Ordering::Equal
+                } else {
+                    a.cmp(b)
+                }
+            })
+    }
+
+    fn compare_primary(&self, a: &str, b: &str) -> Ordering {
+        if self.locale.is_some() {
+            fold_diacritics(a).cmp(&fold_diacritics(b))
+        } else if self.case_insensitive {
+            a.to_lowercase().cmp(&b.to_lowercase())
+        } else {
+            a.cmp(b)
+        }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn compare_intl(&self, locale: &str, a: &str, b: &str) -> Ordering {
+        use leptos::wasm_bindgen::JsValue;
+        use std::cell::RefCell;
+        use std::collections::HashMap;
+
+        thread_local! {
+            static COMPARE_FNS: RefCell<HashMap<Collation, js_sys::Function>> = RefCell::new(HashMap::new());
+        }
+
+        let compare = COMPARE_FNS.with(|compare_fns| {
+            compare_fns
+                .borrow_mut()
+                .entry(*self)
+                .or_insert_with(|| {
+                    let locales = js_sys::Array::new();
+                    if !locale.is_empty() {
+                        locales.push(&JsValue::from_str(locale));
+                    }
+
+                    let sensitivity = if self.case_insensitive {
+                        "accent"
+                    } else {
+                        "variant"
+                    };
+
+                    let options = js_sys::Object::new();
+                    let _ = js_sys::Reflect::set(&options, &"numeric".into(), &self.natural.into());
+                    let _ =
+                        js_sys::Reflect::set(&options, &"sensitivity".into(), &sensitivity.into());
+
+                    js_sys::Intl::Collator::new(&locales, &options).compare()
+                })
+                .clone()
+        });
+
+        compare
+            .call2(&JsValue::NULL, &a.into(), &b.into())
+            .ok()
+            .and_then(|result| result.as_f64())
+            .and_then(|result| result.partial_cmp(&0.0))
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+/// Splits both strings into runs of digits and other characters. Runs of digits are compared by their numeric
+/// value, all other runs with `compare_text`.
+fn compare_natural(a: &str, b: &str, compare_text: impl Fn(&str, &str) -> Ordering) -> Ordering {
+    let mut a_runs = digit_runs(a);
+    let mut b_runs = digit_runs(b);
+
+    loop {
+        let ordering = match (a_runs.next(), b_runs.next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(a), Some(b)) => {
+                if is_digits(a) && is_digits(b) {
+                    compare_digits(a, b)
+                } else {
+                    compare_text(a, b)
+                }
+            }
+        };
+
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+}
+
+fn digit_runs(text: &str) -> impl Iterator<Item = &str> {
+    let mut rest = text;
+
+    std::iter::from_fn(move || {
+        let first = rest.chars().next()?;
+        let end = rest
+            .find(|c: char| c.is_ascii_digit() != first.is_ascii_digit())
+            .unwrap_or(rest.len());
+
+        let (run, remaining) = rest.split_at(end);
+        rest = remaining;
+
+        Some(run)
+    })
+}
+
+fn is_digits(run: &str) -> bool {
+    run.starts_with(|c: char| c.is_ascii_digit())
+}
+
+fn compare_digits(a: &str, b: &str) -> Ordering {
+    let a = a.trim_start_matches('0');
+    let b = b.trim_start_matches('0');
+
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
+/// Lowercases `text` and replaces common Latin letters with diacritics by their base letters.
+fn fold_diacritics(text: &str) -> String {
+    let mut folded = String::with_capacity(text.len());
+
+    for c in text.chars().flat_map(char::to_lowercase) {
+        match c {
+            'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ą' => folded.push('a'),
+            'æ' => folded.push_str("ae"),
+            'ç' | 'ć' | 'č' => folded.push('c'),
+            'ð' | 'ď' => folded.push('d'),
+            'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ę' | 'ě' => folded.push('e'),
+            'ì' | 'í' | 'î' | 'ï' | 'ī' => folded.push('i'),
+            'ł' => folded.push('l'),
+            'ñ' | 'ń' | 'ň' => folded.push('n'),
+            'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ő' => folded.push('o'),
+            'œ' => folded.push_str("oe"),
+            'ř' => folded.push('r'),
+            'ß' => folded.push_str("ss"),
+            'ś' | 'š' => folded.push('s'),
+            'ť' => folded.push('t'),
+            'þ' => folded.push_str("th"),
+            'ù' | 'ú' | 'û' | 'ü' | 'ū' | 'ů' | 'ű' => folded.push('u'),
+            'ý' | 'ÿ' => folded.push('y'),
+            'ź' | 'ż' | 'ž' => folded.push('z'),
+            _ => folded.push(c),
+        }
+    }
+
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sorted(collation: Collation, words: &[&'static str]) -> Vec<&'static str> {
+        let mut words = words.to_vec();
+        words.sort_by(|a, b| collation.compare(a, b));
+        words
+    }
+
+    #[test]
+    fn default_compares_bytes() {
+        assert_eq!(
+            sorted(
+                Collation::default(),
+                &["b", "Ärzte", "a", "B", "Item 10", "Item 2"]
+            ),
+            vec!["B", "Item 10", "Item 2", "a", "b", "Ärzte"]
+        );
+    }
+
+    #[test]
+    fn case_insensitive_ignores_the_case() {
+        let collation = Collation {
+            case_insensitive: true,
+            ..Default::default()
+        };
+
+        assert_eq!(collation.compare("apple", "Apple"), Ordering::Equal);
+        assert_eq!(
+            sorted(collation, &["b", "A", "a", "B"]),
+            vec!["A", "a", "b", "B"]
+        );
+    }
+
+    #[test]
+    fn natural_compares_numbers_by_value() {
+        let collation = Collation {
+            natural: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            sorted(collation, &["Item 10", "Item 2", "Item 1", "Item"]),
+            vec!["Item", "Item 1", "Item 2", "Item 10"]
+        );
+        assert_eq!(collation.compare("v1.9", "v1.10"), Ordering::Less);
+    }
+
+    #[test]
+    fn natural_ignores_leading_zeros_but_stays_total() {
+        let collation = Collation {
+            natural: true,
+            ..Default::default()
+        };
+
+        assert_eq!(collation.compare("Item 007", "Item 10"), Ordering::Less);
+        assert_eq!(collation.compare("Item 010", "Item 9"), Ordering::Greater);
+        assert_ne!(collation.compare("Item 02", "Item 2"), Ordering::Equal);
+    }
+
+    #[test]
+    fn locale_sorts_diacritics_next_to_their_base_letter() {
+        let collation = Collation {
+            case_insensitive: true,
+            locale: Some("de-DE"),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            sorted(
+                collation,
+                &["Zebra", "Ärzte", "Affe", "Öl", "Ofen", "Straße", "Strasse"]
+            ),
+            vec!["Affe", "Ärzte", "Ofen", "Öl", "Strasse", "Straße", "Zebra"]
+        );
+    }
+
+    #[test]
+    fn locale_is_not_tailored_outside_of_the_browser() {
+        let collation = Collation {
+            locale: Some("sv"),
+            ..Default::default()
+        };
+
+        // in Swedish "Å" comes after "Z", but only Intl.Collator knows that
+        assert_eq!(collation.compare("Åsa", "Zorro"), Ordering::Less);
+    }
+
+    #[test]
+    fn locale_and_natural_combined() {
+        let collation = Collation {
+            natural: true,
+            locale: Some("de-DE"),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            sorted(collation, &["Übung 10", "Übung 2", "Uhr"]),
+            vec!["Übung 2", "Übung 10", "Uhr"]
+        );
+    }
+}
@@ -0,0 +1,45 @@
+use leptos::*;
+
+/// The default renderer for the header cell of the actions column. Renders a `<th>` with the title.
+/// The actions column can't be sorted, so the cell doesn't react to clicks.
+#[component]
+pub fn DefaultTableActionsHeadRenderer(
+    /// The class attribute for the head element. Generated by the classes provider.
+    #[prop(into)]
+    class: String,
+    /// The title of the column. Defaults to `"Actions"`.
+    #[prop(into, default = "Actions".to_string())]
+    title: String,
+) -> impl IntoView {
+    view! {
+        <th class=class scope="col">
+            {title}
+        </th>
+    }
+}
+
+/// The default renderer for the cells of the actions column. Renders a `<td>` that contains the children,
+/// usually buttons. Render it from a custom row renderer, see the crate documentation.
+///
+/// Clicks, double clicks and key presses inside of this cell don't propagate to the row, so pressing
+/// a button doesn't also select the row or trigger any of the row events.
+#[component]
+pub fn DefaultTableActionsCellRenderer(
+    /// The class attribute for the cell element. Generated by the classes provider.
+    #[prop(into)]
+    class: String,
+    children: Children,
+) -> impl IntoView {
+    view! {
+        <td
+            class=class
+            on:click=|event| event.stop_propagation()
+            on:dblclick=|event| event.stop_propagation()
+            on:contextmenu=|event| event.stop_propagation()
+            on:pointerdown=|event| event.stop_propagation()
+            on:keydown=|event| event.stop_propagation()
+        >
+            {children()}
+        </td>
+    }
+}
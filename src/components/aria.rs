@@ -0,0 +1,76 @@
+use crate::ColumnSort;
+use leptos::*;
+use std::collections::VecDeque;
+
+/// Inline style that hides an element visually but keeps it readable for screen readers.
+pub const VISUALLY_HIDDEN_STYLE: &str = "position: absolute; width: 1px; height: 1px; padding: 0; margin: -1px; overflow: hidden; clip: rect(0, 0, 0, 0); white-space: nowrap; border: 0;";
+
+/// The ARIA semantics of a table.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum TableRole {
+    /// Plain table semantics. Screen readers read the table as static data. This is the default.
+    #[default]
+    Table,
+    /// Interactive grid semantics (`role="grid"`). Use this if the cells can be navigated with the keyboard,
+    /// rows can be selected or cells can be edited.
+    Grid,
+}
+
+impl TableRole {
+    /// The value of the `role` attribute of the `<table>` element.
+    /// `None` for [`TableRole::Table`] because that is the native role of `<table>`.
+    pub fn as_role(&self) -> Option<&'static str> {
+        match self {
+            TableRole::Table => None,
+            TableRole::Grid => Some("grid"),
+        }
+    }
+}
+
+/// An `aria-live` region that is hidden visually. Screen readers read the message whenever it changes,
+/// for example when the sorting changed or the rows finished loading.
+/// [`DynamicTable`](crate::DynamicTable) renders this right after the table.
+#[component]
+pub fn TableAnnouncer(
+    /// The message to announce.
+    #[prop(into)]
+    message: Signal<String>,
+) -> impl IntoView {
+    view! {
+        <div role="status" aria-live="polite" aria-atomic="true" style=VISUALLY_HIDDEN_STYLE>
+            {message}
+        </div>
+    }
+}
+
+/// The message announced after the sorting changed, e.g. `"Sorted by Title ascending, then Year descending"`.
+/// `title` returns the displayed title of a column.
+pub fn sort_announcement<C>(
+    sorting: &VecDeque<(C, ColumnSort)>,
+    title: impl Fn(&C) -> String,
+) -> String {
+    let sorted_columns = sorting
+        .iter()
+        .filter_map(|(column, sort)| match sort {
+            ColumnSort::Ascending => Some(format!("{} ascending", title(column))),
+            ColumnSort::Descending => Some(format!("{} descending", title(column))),
+            ColumnSort::None => None,
+        })
+        .collect::<Vec<_>>();
+
+    if sorted_columns.is_empty() {
+        "Not sorted".to_string()
+    } else {
+        format!("Sorted by {}", sorted_columns.join(", then "))
+    }
+}
+
+/// The message announced after rows finished loading. Pass it to the [`TableAnnouncer`] of your own table component
+/// when the loading placeholder rows are replaced by `row_count` rows.
+pub fn load_announcement(row_count: usize) -> String {
+    match row_count {
+        0 => "No rows".to_string(),
+        1 => "1 row loaded".to_string(),
+        row_count => format!("{row_count} rows loaded"),
+    }
+}
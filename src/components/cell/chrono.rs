@@ -1,6 +1,7 @@
 #![allow(unused_variables)]
 #![doc(cfg(feature = "chrono"))]
 
+use super::create_focusable_cell;
 use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
 use leptos::*;
 use paste::paste;
@@ -26,14 +27,19 @@ macro_rules! date_cell_renderer {
                 /// The format string to use for formatting the date. Provided by the `#[table(format(string="..."))]` attribute of the field.
                 /// See [`chrono::format::strftime`] for more information.
                 #[prop(optional)] format_string: Option<String>,
+                /// Whether this cell is the focused cell for keyboard navigation. Only passed if keyboard navigation is used,
+                /// e.g. by [`DynamicTable`](crate::DynamicTable). See [`create_roving_tabindex`](crate::create_roving_tabindex).
+                #[prop(optional)] focused: Option<Signal<bool>>,
             ) -> impl IntoView {
                 let text = match format_string {
                     Some(format_string) => create_memo( move |_| value().format(&format_string).to_string()),
                     None => create_memo( move |_| value().to_string()),
                 };
 
+                let (td_ref, tabindex) = create_focusable_cell(focused);
+
                 view! {
-                    <td class=class>{text}</td>
+                    <td class=class node_ref=td_ref tabindex=tabindex>{text}</td>
                 }
             }
         }
@@ -0,0 +1,44 @@
+use leptos::*;
+use std::time::Duration;
+
+/// A sensible duration for [`create_change_flash`].
+pub const DEFAULT_FLASH_DURATION: Duration = Duration::from_millis(1000);
+
+/// Creates a signal that is `true` for `duration` after `value` changed and `false` otherwise.
+/// Values are compared with `PartialEq` so setting the same value again doesn't trigger a flash.
+/// The initial value doesn't trigger a flash either.
+///
+/// Use it in a custom cell renderer to append the classes returned by
+/// [`TableClassesProvider::cell_changed`](crate::TableClassesProvider::cell_changed) while the signal is `true`.
+pub fn create_change_flash<T>(value: MaybeSignal<T>, duration: Duration) -> Signal<bool>
+where
+    T: PartialEq + Clone + 'static,
+{
+    let (flashing, set_flashing) = create_signal(false);
+    let timeout = store_value(None::<TimeoutHandle>);
+
+    create_effect(move |previous: Option<T>| {
+        let current = value.get();
+
+        if matches!(previous, Some(previous) if previous != current) {
+            if let Some(handle) = timeout.get_value() {
+                handle.clear();
+            }
+
+            set_flashing.set(true);
+
+            timeout
+                .set_value(set_timeout_with_handle(move || set_flashing.set(false), duration).ok());
+        }
+
+        current
+    });
+
+    on_cleanup(move || {
+        if let Some(handle) = timeout.get_value() {
+            handle.clear();
+        }
+    });
+
+    flashing.into()
+}
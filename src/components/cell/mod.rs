@@ -5,8 +5,13 @@ mod chrono;
 #[cfg(feature = "chrono")]
 pub use self::chrono::*;
 
+mod flash;
+pub use flash::*;
+
+use crate::create_roving_tabindex;
 use core::fmt::Display;
 use leptos::*;
+use std::rc::Rc;
 
 /// The default cell renderer. Uses the `<td>` element.
 #[component]
@@ -19,12 +24,28 @@ pub fn DefaultTableCellRenderer<T>(
     value: MaybeSignal<T>,
     /// The index of the column. Starts at 0.
     index: usize,
+    /// Whether this cell is the focused cell for keyboard navigation. Only passed if keyboard navigation is used,
+    /// e.g. by [`DynamicTable`](crate::DynamicTable). See [`create_roving_tabindex`].
+    #[prop(optional)]
+    focused: Option<Signal<bool>>,
+    /// Called with the index of the column when the cell is clicked.
+    /// [`DynamicTable`](crate::DynamicTable) uses it to move the focus to the clicked cell.
+    #[prop(optional)]
+    on_click: Option<Rc<dyn Fn(usize)>>,
 ) -> impl IntoView
 where
     T: IntoView + Clone + 'static,
 {
+    let (td_ref, tabindex) = create_focusable_cell(focused);
+
+    let on_click = move |_| {
+        if let Some(on_click) = &on_click {
+            on_click(index);
+        }
+    };
+
     view! {
-        <td class=class>{value}</td>
+        <td class=class node_ref=td_ref tabindex=tabindex on:click=on_click>{value}</td>
     }
 }
 
@@ -42,6 +63,10 @@ pub fn DefaultNumberTableCellRenderer<T>(
     /// The number of digits to display after the decimal point. Provided by the `#[table(format(precision=X))]` attribute of the field.
     #[prop(optional)]
     precision: Option<usize>,
+    /// Whether this cell is the focused cell for keyboard navigation. Only passed if keyboard navigation is used,
+    /// e.g. by [`DynamicTable`](crate::DynamicTable). See [`create_roving_tabindex`].
+    #[prop(optional)]
+    focused: Option<Signal<bool>>,
 ) -> impl IntoView
 where
     T: Display + Clone + 'static,
@@ -51,7 +76,24 @@ where
         None => format!("{}", value()),
     });
 
+    let (td_ref, tabindex) = create_focusable_cell(focused);
+
     view! {
-        <td class=class>{text}</td>
+        <td class=class node_ref=td_ref tabindex=tabindex>{text}</td>
     }
 }
+
+/// Creates the node ref and the roving `tabindex` for a `<td>` of a cell renderer.
+pub(crate) fn create_focusable_cell(
+    focused: Option<Signal<bool>>,
+) -> (NodeRef<html::Td>, impl Fn() -> Option<&'static str> + Copy) {
+    let td_ref = create_node_ref::<html::Td>();
+
+    let tabindex = create_roving_tabindex(focused, move || {
+        if let Some(td) = td_ref.get_untracked() {
+            let _ = td.focus();
+        }
+    });
+
+    (td_ref, tabindex)
+}
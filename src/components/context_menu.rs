@@ -0,0 +1,192 @@
+use crate::{RowAction, RowActionTargets, TableRowEvent};
+use leptos::*;
+
+/// The state of an open row context menu: where it is displayed and which rows the actions apply to.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RowContextMenuState<K, T> {
+    /// The horizontal position of the menu in pixels relative to the viewport.
+    pub x: i32,
+    /// The vertical position of the menu in pixels relative to the viewport.
+    pub y: i32,
+    /// The rows the actions apply to. See [`row_action_targets`](crate::row_action_targets).
+    pub rows: RowActionTargets<K, T>,
+}
+
+impl<K, T> RowContextMenuState<K, T> {
+    /// Opens the menu at the position of the pointer of the event. Events that weren't triggered by a pointer
+    /// open the menu at the top left corner.
+    pub fn at_event(event: &TableRowEvent<K>, rows: RowActionTargets<K, T>) -> Self {
+        let (x, y) = event
+            .trigger
+            .mouse_event()
+            .map(|mouse_event| (mouse_event.client_x(), mouse_event.client_y()))
+            .unwrap_or_default();
+
+        Self { x, y, rows }
+    }
+}
+
+/// The default renderer of the row context menu. Renders a list of buttons at the given position
+/// and closes when an action was chosen, Escape was pressed or somewhere outside of the menu was clicked.
+/// The first item is focused when the menu opens. The arrow keys, Home and End move the focus between the items.
+/// Disabled items can be focused but not chosen, as screen readers expect from a `role="menu"`.
+/// Render this while a [`RowContextMenuState`] is set, for example after the `on_context_menu` handler of
+/// [`DefaultTableRowRenderer`](crate::DefaultTableRowRenderer) stored it in a signal.
+#[component]
+pub fn DefaultRowContextMenuRenderer<K, T, F>(
+    /// The class attribute for the menu element. Generated by the classes provider.
+    #[prop(into)]
+    class: String,
+    /// The class attribute for enabled menu items. Generated by the classes provider.
+    #[prop(into)]
+    item_class: String,
+    /// The class attribute for disabled menu items. Generated by the classes provider.
+    #[prop(into)]
+    disabled_item_class: String,
+    /// The class attribute for the icon of a menu item. Generated by the classes provider.
+    #[prop(into)]
+    icon_class: String,
+    /// The actions to display.
+    actions: Vec<RowAction<K, T>>,
+    /// The position of the menu and the rows the actions apply to.
+    state: RowContextMenuState<K, T>,
+    /// Called when the menu should be closed.
+    on_close: F,
+) -> impl IntoView
+where
+    K: Clone + 'static,
+    T: Clone + 'static,
+    F: Fn() + Copy + 'static,
+{
+    let item_refs = store_value(
+        (0..actions.len())
+            .map(|_| create_node_ref::<html::Button>())
+            .collect::<Vec<_>>(),
+    );
+
+    let focus_item = move |index: usize| {
+        item_refs.with_value(|item_refs| {
+            if let Some(item) = item_refs.get(index).and_then(|item| item.get_untracked()) {
+                let _ = item.focus();
+            }
+        });
+    };
+
+    let focused_item = move || {
+        let active_element = document().active_element();
+
+        item_refs.with_value(|item_refs| {
+            item_refs.iter().position(|item| {
+                item.get_untracked().is_some_and(|item| {
+                    let item: &web_sys::Element = &item;
+                    Some(item) == active_element.as_ref()
+                })
+            })
+        })
+    };
+
+    let menu_ref = create_node_ref::<html::Ul>();
+    menu_ref.on_load(move |menu| {
+        if item_refs.with_value(Vec::is_empty) {
+            let _ = menu.focus();
+        } else {
+            focus_item(0);
+        }
+    });
+
+    let on_keydown = move |event: ev::KeyboardEvent| {
+        let item_count = item_refs.with_value(Vec::len);
+        let focused = focused_item();
+
+        let next = match event.key().as_str() {
+            "Escape" => {
+                event.prevent_default();
+                on_close();
+                return;
+            }
+            _ if item_count == 0 => return,
+            "ArrowDown" => focused.map_or(0, |index| (index + 1) % item_count),
+            "ArrowUp" => focused.map_or(item_count - 1, |index| {
+                (index + item_count - 1) % item_count
+            }),
+            "Home" => 0,
+            "End" => item_count - 1,
+            _ => return,
+        };
+
+        event.prevent_default();
+        focus_item(next);
+    };
+
+    let menu_style = format!(
+        "position: fixed; left: {}px; top: {}px; z-index: 1001;",
+        state.x, state.y
+    );
+    let rows = store_value(state.rows);
+
+    let items = actions
+        .into_iter()
+        .enumerate()
+        .map(|(index, action)| {
+            let enabled = rows.with_value(|rows| action.is_enabled(rows));
+            let class = if enabled {
+                item_class.clone()
+            } else {
+                disabled_item_class.clone()
+            };
+            let icon = action.icon.clone().map(
+                |icon| view! { <span class=icon_class.clone() aria-hidden="true">{icon}</span> },
+            );
+            let label = action.label.clone();
+
+            let on_click = move |_| {
+                if !enabled {
+                    return;
+                }
+
+                let action = action.clone();
+                let rows = rows.get_value();
+                on_close();
+                spawn_local(async move { action.run(rows).await });
+            };
+
+            view! {
+                <li role="none">
+                    <button
+                        type="button"
+                        role="menuitem"
+                        class=class
+                        tabindex="-1"
+                        aria-disabled=(!enabled).then_some("true")
+                        node_ref=item_refs.with_value(|item_refs| item_refs[index])
+                        on:click=on_click
+                    >
+                        {icon}
+                        {label}
+                    </button>
+                </li>
+            }
+        })
+        .collect_view();
+
+    view! {
+        <div
+            style="position: fixed; inset: 0; z-index: 1000;"
+            on:click=move |_| on_close()
+            on:contextmenu=move |event| {
+                event.prevent_default();
+                on_close();
+            }
+        ></div>
+        <ul
+            role="menu"
+            tabindex="-1"
+            class=class
+            style=menu_style
+            node_ref=menu_ref
+            on:keydown=on_keydown
+        >
+            {items}
+        </ul>
+    }
+}
@@ -0,0 +1,439 @@
+use crate::{
+    column_sort, column_sort_priority, handle_navigation_key, sort_announcement, sort_by_columns,
+    toggle_column_sort_with, CellValue, CellValueType, Collation, ColumnSort,
+    DefaultTableCellRenderer, DefaultTableEmptyRowRenderer, DefaultTableHeaderRenderer,
+    DefaultTableRowRenderer, FocusedCell, SortOptions, TableAnnouncer, TableClassesProvider,
+    TableHeadEvent, TableRole, TableRowEvent,
+};
+use leptos::*;
+use serde_json::Value;
+use std::collections::VecDeque;
+use std::fmt::{Debug, Formatter};
+use std::rc::Rc;
+
+/// A row of a [`DynamicTable`]. Contains one value per column in the order of the columns.
+pub type DynamicRow = Vec<CellValue>;
+
+/// Renders a cell of a [`DynamicTable`]. Receives the value, the class attribute generated by the
+/// classes provider and the index of the column.
+pub type DynamicCellRendererFn = Rc<dyn Fn(CellValue, String, usize) -> View>;
+
+/// How the cells of a column of a [`DynamicTable`] are rendered.
+#[derive(Clone, Default)]
+pub enum DynamicCellRenderer {
+    /// Renders the value formatted with the precision and format string of the column
+    /// with [`DefaultTableCellRenderer`].
+    #[default]
+    Default,
+    /// Renders the cells with the given function.
+    Custom(DynamicCellRendererFn),
+}
+
+impl Debug for DynamicCellRenderer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Default => write!(f, "Default"),
+            Self::Custom(_) => write!(f, "Custom"),
+        }
+    }
+}
+
+impl PartialEq for DynamicCellRenderer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Default, Self::Default) => true,
+            (Self::Custom(a), Self::Custom(b)) => Rc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+/// Describes a column of a [`DynamicTable`]. This is the runtime equivalent of a struct field with `#[table(...)]` attributes.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DynamicColumn {
+    /// The name of the column. Used as key when rows are created from JSON objects with [`dynamic_rows_from_json`].
+    pub name: String,
+    /// The title that is displayed in the header cell.
+    pub title: String,
+    /// The type that JSON values are converted to.
+    pub value_type: CellValueType,
+    /// How the cells are rendered.
+    pub renderer: DynamicCellRenderer,
+    /// The number of digits to display after the decimal point for numbers.
+    pub precision: Option<usize>,
+    /// The format string for dates and times. See [`chrono::format::strftime`] for more information.
+    pub format_string: Option<String>,
+    /// If `false` clicking the header doesn't sort by this column. Defaults to `true`.
+    pub sortable: bool,
+    /// The direction this column is sorted by when its header is clicked for the first time. Defaults to ascending.
+    pub initial_sort: ColumnSort,
+    /// How text values are compared when sorting by this column. See [`Collation`].
+    pub collation: Collation,
+    /// The classes that are applied to the header cell of this column.
+    pub head_class: String,
+    /// The classes that are applied to the body cells of this column.
+    pub cell_class: String,
+}
+
+impl DynamicColumn {
+    /// Creates a sortable column with the given name and title.
+    pub fn new(name: impl Into<String>, title: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            title: title.into(),
+            value_type: CellValueType::default(),
+            renderer: DynamicCellRenderer::default(),
+            precision: None,
+            format_string: None,
+            sortable: true,
+            initial_sort: ColumnSort::Ascending,
+            collation: Collation::default(),
+            head_class: String::new(),
+            cell_class: String::new(),
+        }
+    }
+
+    /// Sets the type that JSON values are converted to.
+    pub fn with_value_type(mut self, value_type: CellValueType) -> Self {
+        self.value_type = value_type;
+        self
+    }
+
+    /// Sets how the cells are rendered.
+    pub fn with_renderer(mut self, renderer: DynamicCellRenderer) -> Self {
+        self.renderer = renderer;
+        self
+    }
+
+    /// Sets the number of digits to display after the decimal point.
+    pub fn with_precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Sets the format string for dates and times.
+    pub fn with_format_string(mut self, format_string: impl Into<String>) -> Self {
+        self.format_string = Some(format_string.into());
+        self
+    }
+
+    /// Sets whether the table can be sorted by this column.
+    pub fn with_sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+
+    /// Sets the direction this column is sorted by when its header is clicked for the first time.
+    pub fn with_initial_sort(mut self, initial_sort: ColumnSort) -> Self {
+        self.initial_sort = initial_sort;
+        self
+    }
+
+    /// Sets how text values are compared when sorting by this column.
+    pub fn with_collation(mut self, collation: Collation) -> Self {
+        self.collation = collation;
+        self
+    }
+
+    /// Sets the classes that are applied to the header cell.
+    pub fn with_head_class(mut self, head_class: impl Into<String>) -> Self {
+        self.head_class = head_class.into();
+        self
+    }
+
+    /// Sets the classes that are applied to the body cells.
+    pub fn with_cell_class(mut self, cell_class: impl Into<String>) -> Self {
+        self.cell_class = cell_class.into();
+        self
+    }
+
+    /// Formats a value of this column for display.
+    pub fn format(&self, value: &CellValue) -> String {
+        value.format(self.precision, self.format_string.as_deref())
+    }
+}
+
+/// Creates rows for a [`DynamicTable`] from JSON objects. For every column the value of the property with the
+/// column's name is converted to the column's [`CellValueType`]. Missing properties become [`CellValue::Empty`].
+pub fn dynamic_rows_from_json(columns: &[DynamicColumn], values: &[Value]) -> Vec<DynamicRow> {
+    values
+        .iter()
+        .map(|value| {
+            columns
+                .iter()
+                .map(|column| {
+                    value
+                        .get(&column.name)
+                        .map(|cell| CellValue::from_json(cell, column.value_type))
+                        .unwrap_or_default()
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// A table whose columns are only known at runtime, for example to display the results of arbitrary queries.
+/// Use this when you can't derive `TableComponent` for a struct.
+///
+/// It uses the same classes providers, renderers and sorting model as the generated components.
+/// The columns are identified by their index, so the `sorting` contains column indices.
+/// The rows are sorted in memory.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// # use serde_json::json;
+/// # fn test() {
+/// let columns = vec![
+///     DynamicColumn::new("name", "Name"),
+///     DynamicColumn::new("price", "Price").with_precision(2),
+/// ];
+///
+/// let rows = dynamic_rows_from_json(
+///     &columns,
+///     &[
+///         json!({ "name": "Apple", "price": 0.5 }),
+///         json!({ "name": "Pear", "price": 0.75 }),
+///     ],
+/// );
+///
+/// view! {
+///     <DynamicTable columns=columns rows=rows classes_provider=TailwindClassesPreset sortable=true />
+/// };
+/// # }
+/// ```
+#[component]
+pub fn DynamicTable<C>(
+    /// The columns of the table.
+    #[prop(into)]
+    columns: MaybeSignal<Vec<DynamicColumn>>,
+    /// The rows of the table. Every row contains one value per column.
+    #[prop(into)]
+    rows: MaybeSignal<Vec<DynamicRow>>,
+    /// The classes provider. See [`TableClassesProvider`].
+    classes_provider: C,
+    /// The classes that are applied to the root table element.
+    #[prop(optional, into)]
+    class: String,
+    /// Specifies that the table should be sortable. This makes the header clickable to toggle sorting.
+    #[prop(optional)]
+    sortable: bool,
+    /// The sorting of the table. A list of column indices and the sort order sorted by priority.
+    /// Pass a signal to read or set the sorting from the outside.
+    #[prop(optional)]
+    sorting: Option<RwSignal<VecDeque<(usize, ColumnSort)>>>,
+    /// Whether the table can be sorted by multiple columns with Shift+click and by how many. See [`SortOptions`].
+    #[prop(optional)]
+    sort_options: SortOptions,
+    /// The message displayed if there are no rows.
+    #[prop(into, default = "No results".to_string())]
+    empty_message: String,
+    /// The cell that is focused for keyboard navigation. The arrow keys, Home, End, PageUp and PageDown move the focus.
+    /// Pass a signal to read or set the focused cell from the outside.
+    #[prop(optional)]
+    focused_cell: Option<RwSignal<Option<FocusedCell>>>,
+    /// The number of rows that PageUp and PageDown move the focus.
+    #[prop(default = 10)]
+    page_size: usize,
+    /// Whether the table is announced as a plain table or as an interactive grid. See [`TableRole`].
+    #[prop(optional)]
+    role: TableRole,
+) -> impl IntoView
+where
+    C: TableClassesProvider + 'static,
+{
+    let sorting = sorting.unwrap_or_else(|| create_rw_signal(VecDeque::new()));
+    let focused_cell = focused_cell.unwrap_or_else(|| create_rw_signal(None));
+
+    // the first header cell can be reached with Tab as long as no cell was focused
+    let is_focused = move |cell: FocusedCell| {
+        Signal::derive(move || focused_cell.get().unwrap_or_default() == cell)
+    };
+
+    // the rows are paired with their original index which is passed to the row renderer as the row key
+    let sorted_rows = create_memo(move |_| {
+        let mut rows = rows.get().into_iter().enumerate().collect::<Vec<_>>();
+
+        sorting.with(|sorting| {
+            columns.with(|columns| {
+                sort_by_columns(&mut rows, sorting, |(_, a), (_, b), column| {
+                    let empty = CellValue::Empty;
+                    let collation = columns
+                        .get(column)
+                        .map(|column| column.collation)
+                        .unwrap_or_default();
+
+                    a.get(column)
+                        .unwrap_or(&empty)
+                        .compare_with(b.get(column).unwrap_or(&empty), &collation)
+                })
+            })
+        });
+
+        rows
+    });
+
+    let announcement = create_rw_signal(String::new());
+    create_effect(move |initialized: Option<()>| {
+        let message = sorting.with(|sorting| {
+            columns.with_untracked(|columns| {
+                sort_announcement(sorting, |index| {
+                    columns
+                        .get(*index)
+                        .map(|column| column.title.clone())
+                        .unwrap_or_default()
+                })
+            })
+        });
+
+        // only announce changes, not the initial sorting
+        if initialized.is_some() {
+            announcement.set(message);
+        }
+    });
+
+    let table_class = classes_provider.table(&class);
+    let head_row_class = classes_provider.head_row("");
+
+    let head_provider = classes_provider.clone();
+    let head_cells = move || {
+        columns
+            .get()
+            .into_iter()
+            .enumerate()
+            .map(|(index, column)| {
+                let provider = head_provider.clone();
+                let head_class = column.head_class.clone();
+                let column_sortable = sortable && column.sortable;
+                let initial_sort = column.initial_sort;
+
+                let sort_direction =
+                    Signal::derive(move || sorting.with(|sorting| column_sort(sorting, &index)));
+                let sort_priority = Signal::derive(move || {
+                    sorting.with(|sorting| column_sort_priority(sorting, &index))
+                });
+
+                view! {
+                    <DefaultTableHeaderRenderer
+                        class=Signal::derive(move || provider.head_cell(sort_direction.get(), &head_class))
+                        inner_class=head_provider.head_cell_inner()
+                        index=index
+                        column=index
+                        sort_priority=sort_priority
+                        sort_direction=sort_direction
+                        on_click=move |event: TableHeadEvent<usize>| {
+                            focused_cell.set(Some(FocusedCell::Head { column: event.index }));
+
+                            if column_sortable {
+                                sorting.update(|sorting| {
+                                    toggle_column_sort_with(
+                                        sorting,
+                                        event.column,
+                                        initial_sort,
+                                        event.trigger.shift_key(),
+                                        &sort_options,
+                                    )
+                                });
+                            }
+                        }
+                        focused=is_focused(FocusedCell::Head { column: index })
+                    >
+                        {column.title}
+                    </DefaultTableHeaderRenderer>
+                }
+            })
+            .collect_view()
+    };
+
+    let body_provider = classes_provider.clone();
+    let body_rows = move || {
+        let columns = columns.get();
+        let rows = sorted_rows.get();
+
+        if rows.is_empty() {
+            return view! {
+                <DefaultTableEmptyRowRenderer
+                    class=body_provider.empty_row()
+                    cell_class=body_provider.cell("")
+                    col_count=columns.len()
+                    message=empty_message.clone()
+                />
+            }
+            .into_view();
+        }
+
+        rows.into_iter()
+            .enumerate()
+            .map(|(row_index, (row_key, row))| {
+                let cells = columns
+                    .iter()
+                    .enumerate()
+                    .map(|(index, column)| {
+                        let value = row.get(index).cloned().unwrap_or_default();
+                        let class = body_provider.cell(&column.cell_class);
+                        let cell = FocusedCell::Body {
+                            row: row_index,
+                            column: index,
+                        };
+                        let focused = is_focused(cell);
+                        let on_click: Rc<dyn Fn(usize)> =
+                            Rc::new(move |_| focused_cell.set(Some(cell)));
+
+                        match &column.renderer {
+                            DynamicCellRenderer::Default => view! {
+                                <DefaultTableCellRenderer
+                                    class=class
+                                    value=column.format(&value)
+                                    index=index
+                                    focused=focused
+                                    on_click=on_click
+                                />
+                            }
+                            .into_view(),
+                            DynamicCellRenderer::Custom(render) => render(value, class, index),
+                        }
+                    })
+                    .collect_view();
+
+                view! {
+                    <DefaultTableRowRenderer
+                        class=body_provider.row(row_index, false, "")
+                        key=row_key
+                        index=row_index
+                        selected=Signal::derive(|| false)
+                        // the cells move the focus themselves because only they know their column
+                        on_click=|_: TableRowEvent<usize>| {}
+                    >
+                        {cells}
+                    </DefaultTableRowRenderer>
+                }
+            })
+            .collect_view()
+    };
+
+    let on_keydown = move |event: ev::KeyboardEvent| {
+        handle_navigation_key(
+            &event,
+            focused_cell,
+            sorted_rows.with_untracked(Vec::len),
+            columns.with_untracked(Vec::len),
+            page_size,
+        );
+    };
+
+    view! {
+        <table
+            class=table_class
+            role=role.as_role()
+            aria-rowcount=move || sorted_rows.with(Vec::len) + 1
+            aria-colcount=move || columns.with(Vec::len)
+            on:keydown=on_keydown
+        >
+            <thead>
+                <tr class=head_row_class aria-rowindex=1>{head_cells}</tr>
+            </thead>
+            <tbody>{body_rows}</tbody>
+        </table>
+        <TableAnnouncer message=announcement />
+    }
+}
@@ -0,0 +1,27 @@
+use leptos::*;
+
+/// The default renderer for the row that is displayed when the data provider returned no rows.
+/// Renders one `<tr>` with a single `<td>` that spans all columns and displays the message.
+/// It is used by [`DynamicTable`](crate::DynamicTable) and can be used in your own table components.
+#[component]
+pub fn DefaultTableEmptyRowRenderer(
+    /// The class attribute for the row element. Generated by the classes provider.
+    #[prop(into)]
+    class: MaybeSignal<String>,
+    /// The class attribute for the cell element. Generated by the classes provider.
+    #[prop(into)]
+    cell_class: String,
+    /// The number of columns of the table. The cell spans all of them.
+    col_count: usize,
+    /// The message to display. Defaults to `"No results"`.
+    #[prop(into, default = "No results".to_string())]
+    message: String,
+) -> impl IntoView {
+    view! {
+        <tr class=class>
+            <td class=cell_class colspan=col_count>
+                {message}
+            </td>
+        </tr>
+    }
+}
@@ -0,0 +1,132 @@
+use leptos::ev::{KeyboardEvent, MouseEvent, PointerEvent, TouchEvent};
+
+/// The browser event that triggered a [`TableRowEvent`](crate::TableRowEvent) or [`TableHeadEvent`](crate::TableHeadEvent).
+#[derive(Debug, Clone)]
+pub enum TableEventTrigger {
+    /// Triggered by a mouse event like `click`, `dblclick` or `mouseenter`.
+    Mouse(MouseEvent),
+    /// Triggered by a pointer event. For example a long press is detected through pointer events.
+    Pointer(PointerEvent),
+    /// Triggered by a touch event. Useful for custom renderers that handle touch events themselves.
+    Touch(TouchEvent),
+    /// The row or header cell was activated with the keyboard, e.g. by pressing Enter on the focused cell.
+    Keyboard(KeyboardEvent),
+    /// Triggered from code without a browser event, e.g. to select a row programmatically.
+    Synthetic,
+}
+
+impl TableEventTrigger {
+    /// The mouse event if the event was triggered by the mouse or a pointer.
+    pub fn mouse_event(&self) -> Option<&MouseEvent> {
+        match self {
+            Self::Mouse(event) => Some(event),
+            Self::Pointer(event) => Some(event),
+            _ => None,
+        }
+    }
+
+    /// The keyboard event if the event was triggered by the keyboard.
+    pub fn keyboard_event(&self) -> Option<&KeyboardEvent> {
+        match self {
+            Self::Keyboard(event) => Some(event),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the Shift key was pressed.
+    pub fn shift_key(&self) -> bool {
+        match self {
+            Self::Mouse(event) => event.shift_key(),
+            Self::Pointer(event) => event.shift_key(),
+            Self::Touch(event) => event.shift_key(),
+            Self::Keyboard(event) => event.shift_key(),
+            Self::Synthetic => false,
+        }
+    }
+
+    /// Returns `true` if the Ctrl key or on macOS the Command key was pressed.
+    pub fn ctrl_key(&self) -> bool {
+        match self {
+            Self::Mouse(event) => event.ctrl_key() || event.meta_key(),
+            Self::Pointer(event) => event.ctrl_key() || event.meta_key(),
+            Self::Touch(event) => event.ctrl_key() || event.meta_key(),
+            Self::Keyboard(event) => event.ctrl_key() || event.meta_key(),
+            Self::Synthetic => false,
+        }
+    }
+
+    /// Calls `prevent_default()` on the browser event.
+    pub fn prevent_default(&self) {
+        match self {
+            Self::Mouse(event) => event.prevent_default(),
+            Self::Pointer(event) => event.prevent_default(),
+            Self::Touch(event) => event.prevent_default(),
+            Self::Keyboard(event) => event.prevent_default(),
+            Self::Synthetic => {}
+        }
+    }
+
+    /// Calls `stop_propagation()` on the browser event.
+    pub fn stop_propagation(&self) {
+        match self {
+            Self::Mouse(event) => event.stop_propagation(),
+            Self::Pointer(event) => event.stop_propagation(),
+            Self::Touch(event) => event.stop_propagation(),
+            Self::Keyboard(event) => event.stop_propagation(),
+            Self::Synthetic => {}
+        }
+    }
+}
+
+/// The kind of interaction of a [`TableRowEvent`](crate::TableRowEvent).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub enum TableEventKind {
+    /// The row was clicked or activated with the keyboard.
+    #[default]
+    Click,
+    /// The row was double clicked.
+    DoubleClick,
+    /// The context menu was requested on the row, usually by a right click.
+    ContextMenu,
+    /// The pointer entered the row.
+    HoverEnter,
+    /// The pointer left the row.
+    HoverLeave,
+    /// The row was pressed for [`LONG_PRESS_DURATION`] without moving further than [`LONG_PRESS_MOVE_TOLERANCE`],
+    /// usually on touch devices.
+    LongPress,
+}
+
+/// How long a row has to be pressed to emit a [`TableEventKind::LongPress`] event.
+pub const LONG_PRESS_DURATION: std::time::Duration = std::time::Duration::from_millis(500);
+
+/// How far in pixels the pointer can move while pressing a row before the long press is cancelled.
+pub const LONG_PRESS_MOVE_TOLERANCE: i32 = 10;
+
+/// Returns `true` if the pointer moved from `start` to `position` further than [`LONG_PRESS_MOVE_TOLERANCE`].
+pub(crate) fn is_long_press_move(start: (i32, i32), position: (i32, i32)) -> bool {
+    let dx = (position.0 - start.0) as i64;
+    let dy = (position.1 - start.1) as i64;
+    let tolerance = LONG_PRESS_MOVE_TOLERANCE as i64;
+
+    dx * dx + dy * dy > tolerance * tolerance
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn small_moves_keep_the_long_press() {
+        assert!(!is_long_press_move((100, 100), (100, 100)));
+        assert!(!is_long_press_move((100, 100), (106, 108)));
+        assert!(!is_long_press_move((100, 100), (90, 100)));
+    }
+
+    #[test]
+    fn larger_moves_cancel_the_long_press() {
+        assert!(is_long_press_move((100, 100), (111, 100)));
+        assert!(is_long_press_move((100, 100), (108, 108)));
+        assert!(is_long_press_move((100, 100), (100, 60)));
+    }
+}
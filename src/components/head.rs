@@ -1,24 +1,36 @@
-use crate::ColumnSort;
+use crate::{create_roving_tabindex, ColumnSort, TableEventTrigger, VISUALLY_HIDDEN_STYLE};
 use leptos::ev::MouseEvent;
 use leptos::*;
 
-/// Event emitted when a table head cell is clicked.
+/// Event emitted when a table head cell is clicked or activated with the keyboard.
 #[derive(Debug)]
 pub struct TableHeadEvent<C: 'static> {
     /// The index of the column. Starts at 0 for the first column. The order of the columns is the same as the order of the fields in the struct.
     pub index: usize,
     /// The column enum variant. It is auto generated from the struct.
     pub column: C,
-    /// The mouse event that triggered the event.
-    pub mouse_event: MouseEvent,
+    /// The browser event that triggered the event.
+    pub trigger: TableEventTrigger,
+}
+
+impl<C: 'static> TableHeadEvent<C> {
+    /// The mouse event that triggered the event, if it was triggered by the mouse or a pointer.
+    #[deprecated(
+        note = "use `trigger.mouse_event()` instead, events can also be triggered by the keyboard"
+    )]
+    pub fn mouse_event(&self) -> Option<&MouseEvent> {
+        self.trigger.mouse_event()
+    }
 }
 
 /// The default table header renderer. Renders roughly
 /// ```html
-/// <th>
+/// <th scope="col" aria-sort="ascending">
 ///    <span>Title</span>
 /// </th>
 /// ```
+/// The sort direction is exposed through `aria-sort`. For secondary sort columns the priority
+/// is added as visually hidden text because screen readers ignore the `--sort-priority` style.
 #[component]
 pub fn DefaultTableHeaderRenderer<C, F>(
     /// The class attribute for the head element. Generated by the classes provider.
@@ -38,7 +50,12 @@ pub fn DefaultTableHeaderRenderer<C, F>(
     #[prop(into)]
     sort_direction: Signal<ColumnSort>,
     /// The event handler for the click event. Has to be called with [`TableHeadEvent`].
+    /// Also called when Enter or Space is pressed while the cell is focused.
     on_click: F,
+    /// Whether this cell is the focused cell for keyboard navigation. Only passed if keyboard navigation is used,
+    /// e.g. by [`DynamicTable`](crate::DynamicTable). See [`create_roving_tabindex`].
+    #[prop(optional)]
+    focused: Option<Signal<bool>>,
     children: Children,
 ) -> impl IntoView
 where
@@ -60,18 +77,50 @@ where
         format!("{} {}", sort, &priority)
     };
 
-    view! {
-        <th class=class
-            on:click=move |mouse_event| on_click(TableHeadEvent {
+    let th_ref = create_node_ref::<html::Th>();
+    let tabindex = create_roving_tabindex(focused, move || {
+        if let Some(th) = th_ref.get_untracked() {
+            let _ = th.focus();
+        }
+    });
+
+    let on_click = store_value(on_click);
+    let emit = move |trigger: TableEventTrigger| {
+        on_click.with_value(|on_click| {
+            on_click(TableHeadEvent {
                 index,
                 column,
-                mouse_event,
+                trigger,
             })
+        })
+    };
+
+    let aria_sort = move || sort_direction.get().as_aria_sort();
+    let priority_text = move || match sort_priority.get() {
+        Some(priority) if priority > 0 => format!(", sort priority {}", priority + 1),
+        _ => String::new(),
+    };
+
+    view! {
+        <th class=class
+            scope="col"
+            aria-sort=aria_sort
+            node_ref=th_ref
+            tabindex=tabindex
+            on:click=move |mouse_event| emit(TableEventTrigger::Mouse(mouse_event))
+            on:keydown=move |keyboard_event| {
+                if matches!(keyboard_event.key().as_str(), "Enter" | " ") {
+                    keyboard_event.prevent_default();
+                    keyboard_event.stop_propagation();
+                    emit(TableEventTrigger::Keyboard(keyboard_event));
+                }
+            }
             style=style
         >
             <span class=inner_class>
                 {children()}
             </span>
+            <span style=VISUALLY_HIDDEN_STYLE>{priority_text}</span>
         </th>
     }
 }
@@ -0,0 +1,41 @@
+use leptos::*;
+
+/// The default renderer for placeholder rows that are displayed while the data is loading.
+/// Renders one `<tr>` with one `<td>` per column which each contain an empty placeholder `<span>`.
+/// The row is hidden from screen readers. Set `aria-busy="true"` on the `<tbody>` while these rows are displayed
+/// instead and announce [`load_announcement`](crate::load_announcement) through the
+/// [`TableAnnouncer`](crate::TableAnnouncer) when the rows finished loading.
+///
+/// The component generated by deriving `TableComponent` doesn't render placeholder rows. Use this in your own
+/// table components.
+#[allow(unused_variables)]
+#[component]
+pub fn DefaultTableLoadingRowRenderer(
+    /// The class attribute for the row element. Generated by the classes provider.
+    #[prop(into)]
+    class: MaybeSignal<String>,
+    /// The class attribute for the cell elements. Generated by the classes provider.
+    #[prop(into)]
+    cell_class: String,
+    /// The class attribute for the placeholder element inside of each cell. Generated by the classes provider.
+    #[prop(into)]
+    inner_class: String,
+    /// The index of the placeholder row. Starts at 0 for the first row.
+    index: usize,
+    /// The number of columns of the table.
+    col_count: usize,
+) -> impl IntoView {
+    view! {
+        <tr class=class aria-hidden="true">
+            {(0..col_count)
+                .map(|_| {
+                    view! {
+                        <td class=cell_class.clone()>
+                            <span class=inner_class.clone()>"\u{00a0}"</span>
+                        </td>
+                    }
+                })
+                .collect_view()}
+        </tr>
+    }
+}
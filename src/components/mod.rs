@@ -1,7 +1,23 @@
+mod actions;
+mod aria;
 mod cell;
+mod context_menu;
+mod dynamic_table;
+mod empty;
+mod event;
 mod head;
+mod loading;
+mod reorder;
 mod row;
 
+pub use actions::*;
+pub use aria::*;
 pub use cell::*;
+pub use context_menu::*;
+pub use dynamic_table::*;
+pub use empty::*;
+pub use event::*;
 pub use head::*;
+pub use loading::*;
+pub use reorder::*;
 pub use row::*;
@@ -0,0 +1,196 @@
+use crate::{ColumnSort, TableClassesProvider};
+use leptos::*;
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::fmt::{Debug, Formatter};
+use std::rc::Rc;
+
+/// Event emitted when a row was dropped at a new position.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableRowReorderEvent<K> {
+    /// The key of the moved row.
+    pub key: K,
+    /// The index of the row before it was moved.
+    pub from_index: usize,
+    /// The index of the row after it was moved.
+    pub new_index: usize,
+}
+
+/// Where a dragged row would be dropped relative to the row under the pointer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DropPosition {
+    Before,
+    After,
+}
+
+/// Everything a row renderer needs to support reordering the rows with drag and drop.
+/// Create one instance per table and pass clones of it to the `reorder` prop of all row renderers.
+/// The clones share the state of the current drag.
+pub struct RowReorder<K> {
+    /// Whether rows can be dragged at the moment. This is `false` while the table is sorted.
+    pub enabled: Signal<bool>,
+    /// The classes of the cell that contains the drag handle.
+    pub handle_class: String,
+    /// The classes that are added to the row while it is dragged.
+    pub dragging_class: String,
+    /// The classes that are added to the row under the pointer if the dragged row would be dropped before it.
+    pub drop_before_class: String,
+    /// The classes that are added to the row under the pointer if the dragged row would be dropped after it.
+    pub drop_after_class: String,
+    on_reorder: Rc<dyn Fn(TableRowReorderEvent<K>)>,
+    dragged_row: Rc<RefCell<Option<(usize, K)>>>,
+}
+
+impl<K> RowReorder<K> {
+    /// Creates a new instance without any classes. `on_reorder` is called when a row was dropped at a new position.
+    pub fn new(
+        enabled: impl Into<Signal<bool>>,
+        on_reorder: impl Fn(TableRowReorderEvent<K>) + 'static,
+    ) -> Self {
+        Self {
+            enabled: enabled.into(),
+            handle_class: String::new(),
+            dragging_class: String::new(),
+            drop_before_class: String::new(),
+            drop_after_class: String::new(),
+            on_reorder: Rc::new(on_reorder),
+            dragged_row: Rc::new(RefCell::new(None)),
+        }
+    }
+
+    /// Sets the classes from a classes provider.
+    pub fn with_classes_provider(mut self, classes_provider: &impl TableClassesProvider) -> Self {
+        self.handle_class = classes_provider.drag_handle();
+        self.dragging_class = classes_provider.row_dragging();
+        self.drop_before_class = classes_provider.row_drop_indicator(DropPosition::Before);
+        self.drop_after_class = classes_provider.row_drop_indicator(DropPosition::After);
+        self
+    }
+
+    /// Called by the row renderer when a row starts to be dragged.
+    pub fn start_drag(&self, index: usize, key: K) {
+        *self.dragged_row.borrow_mut() = Some((index, key));
+    }
+
+    /// Called by the row renderer when the drag ended without a drop.
+    pub fn cancel_drag(&self) {
+        self.dragged_row.borrow_mut().take();
+    }
+
+    /// Called by the row renderer when the dragged row was dropped on the row at `target_index`.
+    /// Calls `on_reorder` if the row was moved.
+    pub fn drop_on(&self, target_index: usize, position: DropPosition) {
+        let dragged_row = self.dragged_row.borrow_mut().take();
+
+        if let Some((from_index, key)) = dragged_row {
+            let new_index = reordered_index(from_index, target_index, position);
+
+            if new_index != from_index {
+                (self.on_reorder)(TableRowReorderEvent {
+                    key,
+                    from_index,
+                    new_index,
+                });
+            }
+        }
+    }
+
+    /// Returns `true` while a row of this table is dragged.
+    pub fn is_dragging(&self) -> bool {
+        self.dragged_row.borrow().is_some()
+    }
+}
+
+impl<K> Clone for RowReorder<K> {
+    fn clone(&self) -> Self {
+        Self {
+            enabled: self.enabled,
+            handle_class: self.handle_class.clone(),
+            dragging_class: self.dragging_class.clone(),
+            drop_before_class: self.drop_before_class.clone(),
+            drop_after_class: self.drop_after_class.clone(),
+            on_reorder: Rc::clone(&self.on_reorder),
+            dragged_row: Rc::clone(&self.dragged_row),
+        }
+    }
+}
+
+impl<K> Debug for RowReorder<K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RowReorder")
+            .field("enabled", &self.enabled)
+            .field("handle_class", &self.handle_class)
+            .field("dragging_class", &self.dragging_class)
+            .field("drop_before_class", &self.drop_before_class)
+            .field("drop_after_class", &self.drop_after_class)
+            .finish()
+    }
+}
+
+/// The default renderer for the header cell above the drag handles of rows that get a [`RowReorder`].
+/// Renders an empty `<th>` so that the header cells stay above their columns. Like the drag handles it is hidden from
+/// screen readers.
+#[component]
+pub fn DefaultTableReorderHeadRenderer(
+    /// The class attribute for the head element. Generated by the classes provider.
+    #[prop(into)]
+    class: String,
+) -> impl IntoView {
+    view! { <th class=class aria-hidden="true"></th> }
+}
+
+/// Rows can only be reordered while the table is not sorted because otherwise the sorting would immediately
+/// move them back.
+pub fn is_reorder_enabled<C>(sorting: &VecDeque<(C, ColumnSort)>) -> bool {
+    sorting.iter().all(|(_, sort)| *sort == ColumnSort::None)
+}
+
+/// The index a row ends up at when the row at `from_index` is dropped before or after the row at `target_index`.
+pub fn reordered_index(from_index: usize, target_index: usize, position: DropPosition) -> usize {
+    let index = match position {
+        DropPosition::Before => target_index,
+        DropPosition::After => target_index + 1,
+    };
+
+    // removing the row first shifts all following rows up by one
+    if from_index < index {
+        index - 1
+    } else {
+        index
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dropping_before_or_after_a_later_row() {
+        assert_eq!(reordered_index(1, 3, DropPosition::Before), 2);
+        assert_eq!(reordered_index(1, 3, DropPosition::After), 3);
+    }
+
+    #[test]
+    fn dropping_before_or_after_an_earlier_row() {
+        assert_eq!(reordered_index(3, 1, DropPosition::Before), 1);
+        assert_eq!(reordered_index(3, 1, DropPosition::After), 2);
+    }
+
+    #[test]
+    fn dropping_next_to_itself_keeps_the_index() {
+        assert_eq!(reordered_index(2, 2, DropPosition::Before), 2);
+        assert_eq!(reordered_index(2, 2, DropPosition::After), 2);
+        assert_eq!(reordered_index(2, 1, DropPosition::After), 2);
+        assert_eq!(reordered_index(2, 3, DropPosition::Before), 2);
+    }
+
+    #[test]
+    fn reordering_is_only_enabled_without_sorting() {
+        assert!(is_reorder_enabled::<u8>(&VecDeque::new()));
+        assert!(is_reorder_enabled(&VecDeque::from([(0, ColumnSort::None)])));
+        assert!(!is_reorder_enabled(&VecDeque::from([
+            (0, ColumnSort::None),
+            (1, ColumnSort::Descending),
+        ])));
+    }
+}
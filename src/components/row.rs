@@ -1,18 +1,51 @@
+use crate::{
+    is_long_press_move, DropPosition, RowReorder, TableEventKind, TableEventTrigger,
+    LONG_PRESS_DURATION,
+};
 use leptos::ev::MouseEvent;
+use leptos::wasm_bindgen::{JsCast, JsValue};
 use leptos::*;
+use std::rc::Rc;
 
-/// Event emitted when a table row is clicked.
-#[derive(Debug)]
+/// Event emitted when a table row is clicked, activated with the keyboard or otherwise interacted with.
+#[derive(Debug, Clone)]
 pub struct TableRowEvent<K: 'static> {
     /// The key of the row. Value of the field of the struct with the `#[table(key)]` attribute.
     pub key: K,
     /// The index of the row. Starts at 0 for the first row.
     pub index: usize,
-    /// The mouse event that triggered the event.
-    pub mouse_event: MouseEvent,
+    /// The kind of interaction.
+    pub kind: TableEventKind,
+    /// The browser event that triggered the event.
+    pub trigger: TableEventTrigger,
 }
 
+impl<K: 'static> TableRowEvent<K> {
+    /// Creates an event without a browser event, for example to activate a row from code.
+    pub fn synthetic(key: K, index: usize, kind: TableEventKind) -> Self {
+        Self {
+            key,
+            index,
+            kind,
+            trigger: TableEventTrigger::Synthetic,
+        }
+    }
+
+    /// The mouse event that triggered the event, if it was triggered by the mouse or a pointer.
+    #[deprecated(
+        note = "use `trigger.mouse_event()` instead, events can also be triggered by the keyboard"
+    )]
+    pub fn mouse_event(&self) -> Option<&MouseEvent> {
+        self.trigger.mouse_event()
+    }
+}
+
+/// Handles the [`TableRowEvent`]s of a row other than clicks. See [`TableEventKind`].
+pub type TableRowEventHandler<K> = Rc<dyn Fn(TableRowEvent<K>)>;
+
 /// The default table row renderer. Uses the `<tr>` element.
+/// Sets `aria-rowindex` to the position of the row in the whole table because with virtualization not all rows are
+/// in the DOM. If the row is `selectable`, `aria-selected` is set from the selected state.
 #[allow(unused_variables)]
 #[component]
 pub fn DefaultTableRowRenderer<K, F>(
@@ -27,21 +60,272 @@ pub fn DefaultTableRowRenderer<K, F>(
     /// The selected state of the row. True, when the row is selected.
     #[prop(into)]
     selected: Signal<bool>,
+    /// Whether the row can be selected. Only then `aria-selected` is set, because on rows of a plain table that
+    /// can't be selected screen readers would announce a meaningless "not selected".
+    #[prop(optional)]
+    selectable: bool,
     /// The event handler for the click event. Has to be called with [`TableRowEvent`].
+    /// Also called when Enter is pressed on a focused cell of the row.
     on_click: F,
+    /// The event handler for double clicks, hovering and long presses. Tell them apart by their [`TableEventKind`].
+    #[prop(optional)]
+    on_event: Option<TableRowEventHandler<K>>,
+    /// The event handler for context menu requests, usually right clicks. The browser's context menu is only
+    /// prevented if this is given.
+    #[prop(optional)]
+    on_context_menu: Option<TableRowEventHandler<K>>,
+    /// Makes the row draggable to reorder the rows. If given, a cell with a drag handle is rendered in front of the other cells.
+    /// Render a [`DefaultTableReorderHeadRenderer`](crate::DefaultTableReorderHeadRenderer) in front of the header cells
+    /// to keep the columns aligned.
+    #[prop(optional)]
+    reorder: Option<RowReorder<K>>,
     children: Children,
 ) -> impl IntoView
 where
     F: Fn(TableRowEvent<K>) + 'static,
     K: Clone + 'static,
 {
-    view! {
-        <tr class=class on:click=move |mouse_event| on_click(TableRowEvent {
-            key: key.clone(),
+    let has_event_handler = on_event.is_some();
+    let has_context_menu_handler = on_context_menu.is_some();
+
+    let on_click = store_value(on_click);
+    let on_event = store_value(on_event);
+    let on_context_menu = store_value(on_context_menu);
+    let key = store_value(key);
+
+    let emit = move |kind: TableEventKind, trigger: TableEventTrigger| {
+        let event = TableRowEvent {
+            key: key.get_value(),
             index,
-            mouse_event,
-        })>
+            kind,
+            trigger,
+        };
+
+        match kind {
+            TableEventKind::Click => on_click.with_value(|on_click| on_click(event)),
+            TableEventKind::ContextMenu => {
+                if let Some(on_context_menu) = on_context_menu.get_value() {
+                    on_context_menu(event);
+                }
+            }
+            _ => {
+                if let Some(on_event) = on_event.get_value() {
+                    on_event(event);
+                }
+            }
+        }
+    };
+
+    let long_press_timer = store_value(None::<TimeoutHandle>);
+    let long_press_start = store_value((0, 0));
+    let long_pressed = store_value(false);
+
+    let cancel_long_press = move || {
+        if let Some(timer) = long_press_timer.get_value() {
+            timer.clear();
+            long_press_timer.set_value(None);
+        }
+    };
+    on_cleanup(cancel_long_press);
+
+    let on_pointerdown = move |pointer_event: ev::PointerEvent| {
+        if !has_event_handler || pointer_event.button() != 0 {
+            return;
+        }
+
+        cancel_long_press();
+        long_pressed.set_value(false);
+        long_press_start.set_value((pointer_event.client_x(), pointer_event.client_y()));
+
+        let timer = set_timeout_with_handle(
+            move || {
+                long_press_timer.set_value(None);
+                long_pressed.set_value(true);
+                emit(
+                    TableEventKind::LongPress,
+                    TableEventTrigger::Pointer(pointer_event),
+                );
+            },
+            LONG_PRESS_DURATION,
+        );
+        long_press_timer.set_value(timer.ok());
+    };
+
+    // scrolling or dragging on touch devices is not a long press
+    let on_pointermove = move |pointer_event: ev::PointerEvent| {
+        let position = (pointer_event.client_x(), pointer_event.client_y());
+
+        if is_long_press_move(long_press_start.get_value(), position) {
+            cancel_long_press();
+        }
+    };
+
+    let tr_ref = create_node_ref::<html::Tr>();
+    let dragging = create_rw_signal(false);
+    let drop_position = create_rw_signal(None::<DropPosition>);
+
+    let handle = reorder.clone().map(|reorder| {
+        let handle_reorder = reorder.clone();
+        let on_dragstart = move |drag_event: ev::DragEvent| {
+            if !handle_reorder.enabled.get_untracked() {
+                drag_event.prevent_default();
+                return;
+            }
+
+            if let Some(data_transfer) = drag_event.data_transfer() {
+                // Firefox only starts dragging if there is some data
+                let _ = data_transfer.set_data("text/plain", &index.to_string());
+                data_transfer.set_effect_allowed("move");
+
+                if let Some(tr) = tr_ref.get_untracked() {
+                    data_transfer.set_drag_image(&tr, 0, 0);
+                }
+            }
+
+            handle_reorder.start_drag(index, key.get_value());
+            dragging.set(true);
+        };
+
+        let enabled = reorder.enabled;
+
+        view! {
+            <td
+                class=reorder.handle_class.clone()
+                draggable=move || enabled.get().to_string()
+                aria-hidden="true"
+                on:dragstart=on_dragstart
+                on:dragend=move |_| {
+                    dragging.set(false);
+                    reorder.cancel_drag();
+                }
+            >
+                "⠿"
+            </td>
+        }
+    });
+
+    let reorder = store_value(reorder);
+
+    let row_class = move || {
+        let mut row_class = class.get();
+
+        reorder.with_value(|reorder| {
+            if let Some(reorder) = reorder {
+                let state_class = match (dragging.get(), drop_position.get()) {
+                    (true, _) => &reorder.dragging_class,
+                    (_, Some(DropPosition::Before)) => &reorder.drop_before_class,
+                    (_, Some(DropPosition::After)) => &reorder.drop_after_class,
+                    _ => return,
+                };
+
+                row_class.push(' ');
+                row_class.push_str(state_class);
+            }
+        });
+
+        row_class
+    };
+
+    let on_dragover = move |drag_event: ev::DragEvent| {
+        let accepts_drop = reorder.with_value(|reorder| {
+            reorder
+                .as_ref()
+                .is_some_and(|reorder| reorder.enabled.get_untracked() && reorder.is_dragging())
+        });
+        if !accepts_drop {
+            return;
+        }
+
+        drag_event.prevent_default();
+
+        let position = match tr_ref.get_untracked() {
+            Some(tr) => {
+                let rect = tr.get_bounding_client_rect();
+                if (drag_event.client_y() as f64) < rect.top() + rect.height() / 2.0 {
+                    DropPosition::Before
+                } else {
+                    DropPosition::After
+                }
+            }
+            None => DropPosition::Before,
+        };
+        drop_position.set(Some(position));
+    };
+
+    let on_drop = move |drag_event: ev::DragEvent| {
+        if let Some(position) = drop_position.get_untracked() {
+            drag_event.prevent_default();
+            drop_position.set(None);
+
+            reorder.with_value(|reorder| {
+                if let Some(reorder) = reorder {
+                    reorder.drop_on(index, position);
+                }
+            });
+        }
+    };
+
+    view! {
+        <tr
+            class=row_class
+            node_ref=tr_ref
+            on:dragover=on_dragover
+            on:dragleave=move |_| drop_position.set(None)
+            on:drop=on_drop
+            aria-selected=move || selectable.then(|| if selected.get() { "true" } else { "false" })
+            aria-rowindex=index + 2
+            on:click=move |mouse_event| {
+                // the click that ends a long press is not a click on its own
+                if long_pressed.get_value() {
+                    long_pressed.set_value(false);
+                    return;
+                }
+                emit(TableEventKind::Click, TableEventTrigger::Mouse(mouse_event))
+            }
+            on:keydown=move |keyboard_event| {
+                if keyboard_event.key() == "Enter" && is_row_or_own_cell(&keyboard_event) {
+                    keyboard_event.prevent_default();
+                    emit(TableEventKind::Click, TableEventTrigger::Keyboard(keyboard_event));
+                }
+            }
+            on:dblclick=move |mouse_event| {
+                emit(TableEventKind::DoubleClick, TableEventTrigger::Mouse(mouse_event))
+            }
+            on:contextmenu=move |mouse_event| {
+                if has_context_menu_handler {
+                    mouse_event.prevent_default();
+                    emit(TableEventKind::ContextMenu, TableEventTrigger::Mouse(mouse_event))
+                }
+            }
+            on:mouseenter=move |mouse_event| {
+                emit(TableEventKind::HoverEnter, TableEventTrigger::Mouse(mouse_event))
+            }
+            on:mouseleave=move |mouse_event| {
+                emit(TableEventKind::HoverLeave, TableEventTrigger::Mouse(mouse_event))
+            }
+            on:pointerdown=on_pointerdown
+            on:pointermove=on_pointermove
+            on:pointerup=move |_| cancel_long_press()
+            on:pointerleave=move |_| cancel_long_press()
+            on:pointercancel=move |_| cancel_long_press()
+        >
+            {handle}
             {children()}
         </tr>
     }
 }
+
+/// Whether the event was dispatched on the row itself or on one of its cells, as opposed to an input or button
+/// inside a cell which handle Enter themselves.
+fn is_row_or_own_cell(event: &ev::KeyboardEvent) -> bool {
+    let (Some(target), Some(row)) = (event.target(), event.current_target()) else {
+        return false;
+    };
+    let parent = target
+        .dyn_ref::<web_sys::Node>()
+        .and_then(|node| node.parent_node())
+        .map(JsValue::from);
+    let row = JsValue::from(row);
+
+    JsValue::from(target) == row || parent == Some(row)
+}
@@ -0,0 +1,279 @@
+use crate::{AbortSignal, TableDataProvider};
+use core::fmt::Debug;
+use leptos::*;
+use std::ops::Range;
+
+/// What a [`TableController`] asks the table to reload.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReloadRequest<K> {
+    /// Reload all rows that are currently loaded.
+    All,
+    /// Reload the rows in this range of row indices.
+    Range(Range<usize>),
+    /// Reload the row with this key. Value of the field of the struct with the `#[table(key)]` attribute.
+    Row(K),
+}
+
+/// Handle to control a mounted table from the outside.
+///
+/// Create one with [`TableController::new`] and share it between the code that loads the rows, e.g. a custom table
+/// component, and the UI around the table. Load the rows with [`load_rows`](TableController::load_rows) and react to
+/// [`take_reload_requests`](TableController::take_reload_requests) in an effect. Then you can tell the table to reload
+/// its data without having to touch the `items` signal and use [`is_loading`](TableController::is_loading) and
+/// [`last_error`](TableController::last_error) to show spinners or error messages around the table.
+///
+/// The component generated by deriving `TableComponent` doesn't accept a controller.
+///
+/// Every load is tagged with a generation. Calling [`cancel_pending_loads`](TableController::cancel_pending_loads)
+/// starts a new generation: all loads of previous generations are aborted and their results are dropped.
+/// This way a slow response for an old sorting can never overwrite the rows for the current one.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// # fn test() {
+/// let controller = TableController::<u32>::new();
+/// let is_loading = controller.is_loading();
+/// let last_error = controller.last_error();
+///
+/// view! {
+///     <button on:click=move |_| controller.refresh() disabled=is_loading>
+///         "Refresh"
+///     </button>
+///     {move || last_error.get().map(|error| view! { <p>"Failed to load data: "{error}</p> })}
+/// };
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct TableController<K: 'static> {
+    reload_requests: RwSignal<Vec<ReloadRequest<K>>>,
+    pending_loads: RwSignal<usize>,
+    last_error: RwSignal<Option<String>>,
+    generation: StoredValue<usize>,
+    running_loads: StoredValue<Vec<AbortSignal>>,
+}
+
+impl<K: 'static> Clone for TableController<K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K: 'static> Copy for TableController<K> {}
+
+impl<K: 'static> Default for TableController<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: 'static> TableController<K> {
+    /// Creates a new controller in the current reactive scope.
+    pub fn new() -> Self {
+        Self {
+            reload_requests: create_rw_signal(vec![]),
+            pending_loads: create_rw_signal(0),
+            last_error: create_rw_signal(None),
+            generation: store_value(0),
+            running_loads: store_value(vec![]),
+        }
+    }
+
+    /// Tells the table that its data is stale and that all loaded rows should be reloaded.
+    pub fn refresh(&self) {
+        self.request(ReloadRequest::All);
+    }
+
+    /// Tells the table that the rows in the given range of row indices should be reloaded.
+    pub fn refresh_range(&self, range: Range<usize>) {
+        self.request(ReloadRequest::Range(range));
+    }
+
+    /// Tells the table that the row with the given key should be reloaded.
+    pub fn invalidate_row(&self, key: K) {
+        self.request(ReloadRequest::Row(key));
+    }
+
+    /// `true` while the table is loading rows.
+    pub fn is_loading(&self) -> Signal<bool> {
+        let pending_loads = self.pending_loads;
+        Signal::derive(move || pending_loads.get() > 0)
+    }
+
+    /// The error message of the last failed load. Is reset to `None` by the next successful load.
+    /// See [`TableDataProvider::try_get_rows`].
+    pub fn last_error(&self) -> Signal<Option<String>> {
+        self.last_error.into()
+    }
+
+    /// Returns all reload requests since the last call and clears them.
+    /// Call this inside of an effect in the code that loads the rows to react to the methods above.
+    /// Reading is tracked so the effect reruns when new requests arrive.
+    pub fn take_reload_requests(&self) -> Vec<ReloadRequest<K>> {
+        self.reload_requests.with(|_| ());
+
+        let mut requests = vec![];
+        self.reload_requests
+            .update_untracked(|pending| std::mem::swap(pending, &mut requests));
+        requests
+    }
+
+    /// Aborts all running loads and makes sure their results are dropped.
+    /// Call this when the sorting changes or everything is refreshed.
+    pub fn cancel_pending_loads(&self) {
+        self.generation.update_value(|generation| *generation += 1);
+
+        for abort_signal in self.running_loads.with_value(Vec::clone) {
+            abort_signal.abort();
+        }
+        self.running_loads.update_value(Vec::clear);
+
+        self.pending_loads.set(0);
+    }
+
+    /// Loads the rows in `range` from `provider` through [`TableDataProvider::try_get_rows_abortable`] while keeping
+    /// [`is_loading`](TableController::is_loading) and [`last_error`](TableController::last_error) up to date.
+    /// Returns `None` if loading failed or if the load has been superseded by a call to
+    /// [`cancel_pending_loads`](TableController::cancel_pending_loads) in the meantime.
+    pub async fn load_rows<T, P>(&self, provider: &P, range: Range<usize>) -> Option<Vec<T>>
+    where
+        T: Debug + PartialEq,
+        P: TableDataProvider<T> + ?Sized,
+    {
+        let generation = self.generation.get_value();
+        let abort_signal = AbortSignal::new();
+
+        self.running_loads
+            .update_value(|loads| loads.push(abort_signal.clone()));
+        self.pending_loads.update(|pending| *pending += 1);
+
+        let result = provider
+            .try_get_rows_abortable(range, abort_signal.clone())
+            .await;
+
+        if abort_signal.is_aborted() || generation != self.generation.get_value() {
+            return None;
+        }
+
+        self.running_loads
+            .update_value(|loads| loads.retain(|load| !load.ptr_eq(&abort_signal)));
+        self.pending_loads
+            .update(|pending| *pending = pending.saturating_sub(1));
+
+        match result {
+            Ok(rows) => {
+                self.last_error.set(None);
+                Some(rows)
+            }
+            Err(error) => {
+                self.last_error.set(Some(error));
+                None
+            }
+        }
+    }
+
+    fn request(&self, request: ReloadRequest<K>) {
+        self.reload_requests
+            .update(|requests| requests.push(request));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use futures::channel::oneshot;
+    use std::cell::RefCell;
+
+    struct DelayedProvider {
+        rows: RefCell<Option<oneshot::Receiver<Vec<u32>>>>,
+        aborted: RefCell<Option<AbortSignal>>,
+    }
+
+    impl DelayedProvider {
+        fn new() -> (Self, oneshot::Sender<Vec<u32>>) {
+            let (sender, receiver) = oneshot::channel();
+
+            let provider = Self {
+                rows: RefCell::new(Some(receiver)),
+                aborted: RefCell::new(None),
+            };
+
+            (provider, sender)
+        }
+    }
+
+    #[async_trait(?Send)]
+    impl TableDataProvider<u32> for DelayedProvider {
+        type ColumnName = ();
+
+        async fn get_rows(&self, _: Range<usize>) -> Vec<u32> {
+            unreachable!()
+        }
+
+        async fn try_get_rows_abortable(
+            &self,
+            _: Range<usize>,
+            abort_signal: AbortSignal,
+        ) -> Result<Vec<u32>, String> {
+            *self.aborted.borrow_mut() = Some(abort_signal);
+
+            let receiver = self.rows.borrow_mut().take().unwrap();
+            receiver.await.map_err(|error| error.to_string())
+        }
+    }
+
+    #[test]
+    fn load_rows_returns_rows() {
+        let runtime = create_runtime();
+        let controller = TableController::<u32>::new();
+        let (provider, sender) = DelayedProvider::new();
+
+        sender.send(vec![1, 2]).unwrap();
+        let rows = futures::executor::block_on(controller.load_rows(&provider, 0..2));
+
+        assert_eq!(rows, Some(vec![1, 2]));
+        assert!(!controller.is_loading().get_untracked());
+        assert_eq!(controller.last_error().get_untracked(), None);
+
+        runtime.dispose();
+    }
+
+    #[test]
+    fn cancel_pending_loads_drops_superseded_load() {
+        let runtime = create_runtime();
+        let controller = TableController::<u32>::new();
+        let (provider, sender) = DelayedProvider::new();
+
+        let (rows, ()) = futures::executor::block_on(async {
+            futures::join!(controller.load_rows(&provider, 0..2), async {
+                assert!(controller.is_loading().get_untracked());
+
+                controller.cancel_pending_loads();
+                sender.send(vec![1, 2]).unwrap();
+            })
+        });
+
+        assert_eq!(rows, None);
+        assert!(provider.aborted.borrow().as_ref().unwrap().is_aborted());
+        assert!(!controller.is_loading().get_untracked());
+        assert_eq!(controller.last_error().get_untracked(), None);
+
+        runtime.dispose();
+    }
+
+    #[test]
+    fn load_rows_reports_errors() {
+        let runtime = create_runtime();
+        let controller = TableController::<u32>::new();
+        let (provider, sender) = DelayedProvider::new();
+
+        drop(sender);
+        let rows = futures::executor::block_on(controller.load_rows(&provider, 0..2));
+
+        assert_eq!(rows, None);
+        assert!(controller.last_error().get_untracked().is_some());
+
+        runtime.dispose();
+    }
+}
@@ -1,4 +1,4 @@
-use crate::ColumnSort;
+use crate::{AbortSignal, ColumnSort};
 use async_trait::async_trait;
 use core::fmt::Debug;
 use std::collections::VecDeque;
@@ -11,6 +11,9 @@ use std::ops::Range;
 /// This way a simple list of items can be passed to the table.
 ///
 /// Please note that because of the use of [`async-trait`](https://docs.rs/async-trait/latest/async_trait/) this documentation is a bit cluttered.
+///
+/// The futures returned by this trait are not `Send`. If you need a provider that can also be used
+/// on a multithreaded server runtime please have a look at [`SendTableDataProvider`](crate::SendTableDataProvider).
 #[async_trait(?Send)]
 pub trait TableDataProvider<T>
 where
@@ -22,10 +25,34 @@ where
     /// Get all data rows for the table specified by the range. This method is called when the table is rendered.
     /// The range is determined by the visible rows and used to virtualize the table.
     /// The parameter `range` is only determined by visibility and may be out of bounds. It is the
-    /// responsibility of the implementation to handle this case. Use [get_vec_range_clamped] to get a
-    /// range that is clamped to the length of the vector.
+    /// responsibility of the implementation to handle this case. Use [get_vec_range_clamped] or
+    /// [get_rows_in_range] to get a range that is clamped to the length of the vector.
     async fn get_rows(&self, range: Range<usize>) -> Vec<T>;
 
+    /// Same as [`get_rows`](TableDataProvider::get_rows) but can report an error message if loading failed.
+    /// [`TableController::load_rows`](crate::TableController::load_rows) calls this method and exposes the error through
+    /// [`TableController::last_error`](crate::TableController::last_error).
+    /// The default implementation calls [`get_rows`](TableDataProvider::get_rows) and never fails.
+    async fn try_get_rows(&self, range: Range<usize>) -> Result<Vec<T>, String> {
+        Ok(self.get_rows(range).await)
+    }
+
+    #[allow(unused_variables)]
+    /// Same as [`try_get_rows`](TableDataProvider::try_get_rows) but receives an [`AbortSignal`] that is aborted
+    /// when the result isn't needed anymore, i.e. when
+    /// [`TableController::cancel_pending_loads`](crate::TableController::cancel_pending_loads) is called while
+    /// the rows are still loading. Implement this to cancel your requests early.
+    /// [`TableController::load_rows`](crate::TableController::load_rows) drops the results of aborted loads
+    /// regardless of whether this is implemented or not.
+    /// The default implementation calls [`try_get_rows`](TableDataProvider::try_get_rows).
+    async fn try_get_rows_abortable(
+        &self,
+        range: Range<usize>,
+        abort_signal: AbortSignal,
+    ) -> Result<Vec<T>, String> {
+        self.try_get_rows(range).await
+    }
+
     #[allow(unused_variables)]
     /// Set the sorting of the table. The sorting is a list of column names and the sort order sorted by priority.
     /// The first entry in the list is the most important one.
@@ -36,6 +63,25 @@ where
     fn set_sorting(&mut self, sorting: &VecDeque<(Self::ColumnName, ColumnSort)>) {
         // by default do nothing
     }
+
+    #[allow(unused_variables)]
+    /// Moves the row at `from_index` to `new_index`, for example when a row was reordered with drag and drop, see
+    /// [`RowReorder`](crate::RowReorder). Both indices refer to the unsorted rows because rows can only be reordered
+    /// while the table isn't sorted.
+    /// The default implementation does nothing. Use [`move_vec_row`] for rows that are kept in a `Vec`.
+    /// Please note that after calling this method, data will be reloaded through [`get_rows`](TableDataProvider::get_rows).
+    fn move_row(&mut self, from_index: usize, new_index: usize) {
+        // by default do nothing
+    }
+
+    #[allow(unused_variables)]
+    /// Saves changed rows, for example after values were pasted into editable cells.
+    /// Every entry is the index of the row in the currently sorted table and the changed row.
+    /// All rows of one edit are passed at once so they can be saved in one batch.
+    /// The default implementation returns an error because the provider is read-only.
+    async fn update_rows(&self, rows: &[(usize, T)]) -> Result<(), String> {
+        Err("This data provider doesn't support updating rows".to_string())
+    }
 }
 
 /// Return `vec[range.start..range.end]` where `range` is clamped to the length of `vec`.
@@ -49,3 +95,73 @@ pub fn get_vec_range_clamped<T: Clone>(vec: &Vec<T>, range: Range<usize>) -> Vec
 
     vec[start..end].to_vec()
 }
+
+/// Return `rows[range.start..range.end]` where `range` is clamped to the length of `rows`.
+/// Unlike [`get_vec_range_clamped`] this returns no rows if the range starts at or after the end of `rows`
+/// so that loading the rows page by page doesn't repeat the last row.
+pub fn get_rows_in_range<T: Clone>(rows: &[T], range: Range<usize>) -> Vec<T> {
+    let start = range.start.min(rows.len());
+    let end = range.end.clamp(start, rows.len());
+
+    rows[start..end].to_vec()
+}
+
+/// Moves the element at `from_index` to `new_index` and shifts the elements in between.
+/// Indices that are out of bounds are clamped to the length of `vec`.
+pub fn move_vec_row<T>(vec: &mut Vec<T>, from_index: usize, new_index: usize) {
+    if from_index >= vec.len() {
+        return;
+    }
+
+    let row = vec.remove(from_index);
+    vec.insert(new_index.min(vec.len()), row);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn move_vec_row_moves_down_and_up() {
+        let mut vec = vec![0, 1, 2, 3, 4];
+        move_vec_row(&mut vec, 1, 3);
+        assert_eq!(vec, vec![0, 2, 3, 1, 4]);
+
+        move_vec_row(&mut vec, 3, 0);
+        assert_eq!(vec, vec![1, 0, 2, 3, 4]);
+    }
+
+    #[test]
+    fn move_vec_row_clamps_the_new_index() {
+        let mut vec = vec![0, 1, 2];
+        move_vec_row(&mut vec, 0, 10);
+        assert_eq!(vec, vec![1, 2, 0]);
+    }
+
+    #[test]
+    fn move_vec_row_ignores_an_out_of_bounds_row() {
+        let mut vec = vec![0, 1, 2];
+        move_vec_row(&mut vec, 3, 0);
+        assert_eq!(vec, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn get_vec_range_clamped_returns_the_last_row_past_the_end() {
+        let vec = vec![0, 1, 2];
+        assert_eq!(get_vec_range_clamped(&vec, 1..10), vec![1, 2]);
+        assert_eq!(get_vec_range_clamped(&vec, 3..5), vec![2]);
+        assert!(get_vec_range_clamped(&Vec::<i32>::new(), 0..5).is_empty());
+    }
+
+    #[test]
+    fn get_rows_in_range_returns_nothing_past_the_end() {
+        let rows = [0, 1, 2];
+        assert_eq!(get_rows_in_range(&rows, 1..10), vec![1, 2]);
+        assert_eq!(get_rows_in_range(&rows, 3..5), Vec::<i32>::new());
+        assert_eq!(get_rows_in_range(&rows, 10..20), Vec::<i32>::new());
+        assert_eq!(
+            get_rows_in_range(&rows, Range { start: 2, end: 1 }),
+            Vec::<i32>::new()
+        );
+    }
+}
@@ -0,0 +1,191 @@
+use super::{for_each_chunk, DEFAULT_EXPORT_CHUNK_SIZE};
+use crate::{TableColumn, TableDataProvider, TableRow};
+use core::fmt::Debug;
+
+/// Writes `rows` as CSV according to [RFC 4180](https://www.rfc-editor.org/rfc/rfc4180) including a header line.
+///
+/// The columns are the ones returned by [`TableColumn::columns`] in that order. The header contains their
+/// [`title`](TableColumn::title)s and the values are the [`cell_text`](TableRow::cell_text)s of the rows.
+///
+/// Texts that start with `=`, `+`, `-`, `@`, a tab or a carriage return are prefixed with `'` so that spreadsheet
+/// applications don't run them as formulas. Numbers like `-1.5` are written unchanged.
+pub fn write_csv<T: TableRow>(rows: &[T]) -> String {
+    let columns = T::ColumnName::columns();
+
+    let mut csv = csv_header(&columns);
+    for row in rows {
+        push_csv_row(&mut csv, row, &columns);
+    }
+
+    csv
+}
+
+/// Loads all rows from `provider` and writes them as CSV. See [`write_csv`] for the format.
+///
+/// The rows are loaded in chunks through [`TableDataProvider::try_get_rows`], so the current sorting
+/// and filters of the provider apply. Use [`download_file`](crate::download_file) to offer the result
+/// as a download in the browser.
+///
+/// ```
+/// # use leptos_struct_table::*;
+/// # #[derive(Debug, PartialEq)] struct Book;
+/// # #[derive(Copy, Clone)] enum BookColumnName {}
+/// # impl TableColumn for BookColumnName { fn columns() -> Vec<Self> { vec![] } fn title(&self) -> String { unreachable!() } }
+/// # impl TableRow for Book { type ColumnName = BookColumnName; fn cell_text(&self, _: BookColumnName) -> String { unreachable!() } }
+/// # async fn export<P: TableDataProvider<Book>>(provider: P) -> Result<(), String> {
+/// let csv = export_csv(&provider).await?;
+///
+/// download_file("books.csv", "text/csv", &csv).map_err(|_| "Download failed".to_string())?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn export_csv<T, P>(provider: &P) -> Result<String, String>
+where
+    T: TableRow + Debug + PartialEq,
+    P: TableDataProvider<T> + ?Sized,
+{
+    export_csv_chunked(provider, DEFAULT_EXPORT_CHUNK_SIZE).await
+}
+
+/// Same as [`export_csv`] but loads `chunk_size` rows at once.
+pub async fn export_csv_chunked<T, P>(provider: &P, chunk_size: usize) -> Result<String, String>
+where
+    T: TableRow + Debug + PartialEq,
+    P: TableDataProvider<T> + ?Sized,
+{
+    let columns = T::ColumnName::columns();
+
+    let mut csv = csv_header(&columns);
+
+    for_each_chunk(provider, chunk_size, |rows| {
+        for row in &rows {
+            push_csv_row(&mut csv, row, &columns);
+        }
+    })
+    .await?;
+
+    Ok(csv)
+}
+
+fn csv_header<C: TableColumn>(columns: &[C]) -> String {
+    let mut csv = String::new();
+
+    push_csv_line(&mut csv, columns.iter().map(|column| column.title()));
+
+    csv
+}
+
+fn push_csv_row<T: TableRow>(csv: &mut String, row: &T, columns: &[T::ColumnName]) {
+    push_csv_line(csv, columns.iter().map(|column| row.cell_text(*column)));
+}
+
+fn push_csv_line(csv: &mut String, fields: impl Iterator<Item = String>) {
+    for (index, field) in fields.enumerate() {
+        if index > 0 {
+            csv.push(',');
+        }
+        push_csv_field(csv, &field);
+    }
+
+    csv.push_str("\r\n");
+}
+
+fn push_csv_field(csv: &mut String, field: &str) {
+    let field = if is_formula(field) {
+        format!("'{field}")
+    } else {
+        field.to_string()
+    };
+
+    // some parsers trim unquoted fields, so leading and trailing spaces are quoted as well
+    if field.contains([',', '"', '\r', '\n']) || field.starts_with(' ') || field.ends_with(' ') {
+        csv.push('"');
+        csv.push_str(&field.replace('"', "\"\""));
+        csv.push('"');
+    } else {
+        csv.push_str(&field);
+    }
+}
+
+/// Whether a spreadsheet application would interpret `field` as a formula.
+fn is_formula(field: &str) -> bool {
+    field.starts_with(['=', '+', '-', '@', '\t', '\r']) && field.parse::<f64>().is_err()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    enum NoteColumn {
+        Title,
+        Text,
+    }
+
+    impl TableColumn for NoteColumn {
+        fn columns() -> Vec<Self> {
+            vec![NoteColumn::Title, NoteColumn::Text]
+        }
+
+        fn title(&self) -> String {
+            format!("{self:?}")
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Note(&'static str, &'static str);
+
+    impl TableRow for Note {
+        type ColumnName = NoteColumn;
+
+        fn cell_text(&self, column: NoteColumn) -> String {
+            match column {
+                NoteColumn::Title => self.0.to_string(),
+                NoteColumn::Text => self.1.to_string(),
+            }
+        }
+    }
+
+    fn csv_line(title: &'static str, text: &'static str) -> String {
+        write_csv(&[Note(title, text)])
+            .strip_prefix("Title,Text\r\n")
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn writes_the_header_and_one_line_per_row() {
+        assert_eq!(
+            write_csv(&[Note("a", "b"), Note("c", "d")]),
+            "Title,Text\r\na,b\r\nc,d\r\n"
+        );
+        assert_eq!(write_csv::<Note>(&[]), "Title,Text\r\n");
+    }
+
+    #[test]
+    fn quotes_fields_with_separators_quotes_and_line_breaks() {
+        assert_eq!(csv_line("a,b", "c"), "\"a,b\",c\r\n");
+        assert_eq!(csv_line("say \"hi\"", "c"), "\"say \"\"hi\"\"\",c\r\n");
+        assert_eq!(csv_line("one\ntwo", "c"), "\"one\ntwo\",c\r\n");
+        assert_eq!(csv_line("one\r\ntwo", "c"), "\"one\r\ntwo\",c\r\n");
+    }
+
+    #[test]
+    fn quotes_leading_and_trailing_spaces() {
+        assert_eq!(csv_line(" a", "b "), "\" a\",\"b \"\r\n");
+        assert_eq!(csv_line("a b", ""), "a b,\r\n");
+    }
+
+    #[test]
+    fn neutralises_formulas() {
+        assert_eq!(csv_line("=1+2", "@SUM(A1)"), "'=1+2,'@SUM(A1)\r\n");
+        assert_eq!(csv_line("+cmd", "-cmd"), "'+cmd,'-cmd\r\n");
+        assert_eq!(csv_line("\tx", "=a,b"), "'\tx,\"'=a,b\"\r\n");
+    }
+
+    #[test]
+    fn keeps_numbers_with_signs() {
+        assert_eq!(csv_line("-1.5", "+3"), "-1.5,+3\r\n");
+        assert_eq!(csv_line("1e-3", "a-b"), "1e-3,a-b\r\n");
+    }
+}
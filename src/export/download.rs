@@ -0,0 +1,48 @@
+use leptos::wasm_bindgen::{JsCast, JsValue};
+use leptos::*;
+use std::time::Duration;
+
+/// Offers `content` as a file download in the browser.
+/// `mime_type` is the type of the content, e.g. `text/csv`.
+///
+/// This only works in the browser.
+pub fn download_file(filename: &str, mime_type: &str, content: &str) -> Result<(), JsValue> {
+    let parts = js_sys::Array::of1(&JsValue::from_str(content));
+    download_parts(filename, mime_type, &parts)
+}
+
+/// Same as [`download_file`] but for binary content like `.xlsx` files.
+pub fn download_bytes(filename: &str, mime_type: &str, content: &[u8]) -> Result<(), JsValue> {
+    let parts = js_sys::Array::of1(&js_sys::Uint8Array::from(content));
+    download_parts(filename, mime_type, &parts)
+}
+
+fn download_parts(filename: &str, mime_type: &str, parts: &js_sys::Array) -> Result<(), JsValue> {
+    let mut options = web_sys::BlobPropertyBag::new();
+    options.type_(mime_type);
+
+    let blob = web_sys::Blob::new_with_u8_array_sequence_and_options(parts, &options)?;
+    let url = web_sys::Url::create_object_url_with_blob(&blob)?;
+
+    let anchor = document()
+        .create_element("a")?
+        .unchecked_into::<web_sys::HtmlAnchorElement>();
+    anchor.set_href(&url);
+    anchor.set_download(filename);
+
+    // Firefox and Safari only start the download if the link is part of the document
+    let body = document().body().ok_or("The document has no body")?;
+    body.append_child(&anchor)?;
+    anchor.click();
+    anchor.remove();
+
+    // revoking the URL right away can abort the download before it started
+    set_timeout(
+        move || {
+            let _ = web_sys::Url::revoke_object_url(&url);
+        },
+        Duration::from_secs(1),
+    );
+
+    Ok(())
+}
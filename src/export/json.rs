@@ -0,0 +1,450 @@
+use super::{for_each_chunk, DEFAULT_EXPORT_CHUNK_SIZE};
+use crate::{TableColumn, TableDataProvider, TableRow};
+use core::fmt::Debug;
+use serde::Serialize;
+use std::rc::Rc;
+
+/// The layout of a JSON export.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum JsonFormat {
+    /// One JSON array that contains all rows.
+    #[default]
+    Array,
+    /// Newline delimited JSON. Every row is a JSON value on its own line.
+    Ndjson,
+}
+
+/// Which values of a row are exported to JSON.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum JsonValues {
+    /// The row is serialized with its `Serialize` implementation. This includes all fields, also the ones with the `skip` attribute.
+    #[default]
+    Raw,
+    /// An object with the column titles as keys and the displayed values as strings.
+    /// Only the columns returned by [`TableColumn::columns`] are included. If several columns have the same title,
+    /// the keys of the later ones get a number appended, e.g. `"Name (2)"`, so that every key is unique.
+    Displayed,
+}
+
+type RowFilterFn<T> = Rc<dyn Fn(&T) -> bool>;
+
+/// Exports rows to JSON or NDJSON.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// # use serde::Serialize;
+/// # #[derive(Debug, PartialEq, Serialize)] struct Book { id: u32 }
+/// # #[derive(Copy, Clone)] enum BookColumnName {}
+/// # impl TableColumn for BookColumnName { fn columns() -> Vec<Self> { vec![] } fn title(&self) -> String { unreachable!() } }
+/// # impl TableRow for Book { type ColumnName = BookColumnName; fn cell_text(&self, _: BookColumnName) -> String { unreachable!() } }
+/// # async fn export<P: TableDataProvider<Book>>(provider: P, selected_key: RwSignal<Option<u32>>) -> Result<(), String> {
+/// let json = JsonExport::new()
+///     .with_format(JsonFormat::Ndjson)
+///     .with_values(JsonValues::Displayed)
+///     .only_rows(move |book: &Book| selected_key.get_untracked() == Some(book.id))
+///     .export(&provider)
+///     .await?;
+///
+/// download_file("books.ndjson", "application/x-ndjson", &json).map_err(|_| "Download failed".to_string())?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct JsonExport<T> {
+    format: JsonFormat,
+    values: JsonValues,
+    chunk_size: usize,
+    row_filter: Option<RowFilterFn<T>>,
+}
+
+impl<T> Default for JsonExport<T> {
+    fn default() -> Self {
+        Self {
+            format: JsonFormat::default(),
+            values: JsonValues::default(),
+            chunk_size: DEFAULT_EXPORT_CHUNK_SIZE,
+            row_filter: None,
+        }
+    }
+}
+
+impl<T> Clone for JsonExport<T> {
+    fn clone(&self) -> Self {
+        Self {
+            format: self.format,
+            values: self.values,
+            chunk_size: self.chunk_size,
+            row_filter: self.row_filter.clone(),
+        }
+    }
+}
+
+impl<T> Debug for JsonExport<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JsonExport")
+            .field("format", &self.format)
+            .field("values", &self.values)
+            .field("chunk_size", &self.chunk_size)
+            .field("row_filter", &self.row_filter.is_some())
+            .finish()
+    }
+}
+
+impl<T> JsonExport<T>
+where
+    T: TableRow + Serialize + Debug + PartialEq,
+{
+    /// Creates an export of all rows as a JSON array of raw values.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether a JSON array or NDJSON is written.
+    pub fn with_format(mut self, format: JsonFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Sets whether the raw or the displayed values are written.
+    pub fn with_values(mut self, values: JsonValues) -> Self {
+        self.values = values;
+        self
+    }
+
+    /// Sets how many rows are loaded from the data provider at once.
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size;
+        self
+    }
+
+    /// Only exports the rows for which `filter` returns `true`, for example the selected rows.
+    pub fn only_rows(mut self, filter: impl Fn(&T) -> bool + 'static) -> Self {
+        self.row_filter = Some(Rc::new(filter));
+        self
+    }
+
+    /// Writes `rows` as JSON.
+    pub fn write(&self, rows: &[T]) -> Result<String, String> {
+        let mut json = String::new();
+        let mut row_count = 0;
+
+        self.write_rows(rows, &mut row_count, &mut |part| json.push_str(part))?;
+        self.write_end(row_count, &mut |part| json.push_str(part));
+
+        Ok(json)
+    }
+
+    /// Loads all rows from `provider` and returns them as JSON.
+    /// The current sorting and filters of the provider apply. See [`JsonExport::export_with`].
+    pub async fn export<P>(&self, provider: &P) -> Result<String, String>
+    where
+        P: TableDataProvider<T> + ?Sized,
+    {
+        let mut json = String::new();
+        self.export_with(provider, |part| json.push_str(part))
+            .await?;
+
+        Ok(json)
+    }
+
+    /// Loads all rows from `provider` in chunks and passes the JSON output piece by piece to `write`.
+    /// This way only one chunk of rows is in memory at a time.
+    pub async fn export_with<P>(
+        &self,
+        provider: &P,
+        mut write: impl FnMut(&str),
+    ) -> Result<(), String>
+    where
+        P: TableDataProvider<T> + ?Sized,
+    {
+        let mut row_count = 0;
+        let mut result = Ok(());
+
+        for_each_chunk(provider, self.chunk_size, |rows| {
+            if result.is_ok() {
+                result = self.write_rows(&rows, &mut row_count, &mut write);
+            }
+        })
+        .await?;
+        result?;
+
+        self.write_end(row_count, &mut write);
+
+        Ok(())
+    }
+
+    fn write_rows(
+        &self,
+        rows: &[T],
+        row_count: &mut usize,
+        write: &mut impl FnMut(&str),
+    ) -> Result<(), String> {
+        let columns = T::ColumnName::columns();
+        let keys = unique_keys(&columns);
+
+        let rows = rows.iter().filter(|row| match &self.row_filter {
+            Some(filter) => filter(row),
+            None => true,
+        });
+
+        for row in rows {
+            let value = match self.values {
+                JsonValues::Raw => serde_json::to_string(row).map_err(|err| err.to_string())?,
+                JsonValues::Displayed => displayed_values_json(row, &columns, &keys),
+            };
+
+            match self.format {
+                JsonFormat::Array => {
+                    write(if *row_count == 0 { "[" } else { "," });
+                    write(&value);
+                }
+                JsonFormat::Ndjson => {
+                    write(&value);
+                    write("\n");
+                }
+            }
+
+            *row_count += 1;
+        }
+
+        Ok(())
+    }
+
+    fn write_end(&self, row_count: usize, write: &mut impl FnMut(&str)) {
+        if self.format == JsonFormat::Array {
+            write(if row_count == 0 { "[]" } else { "]" });
+        }
+    }
+}
+
+/// The column titles as JSON object keys. Duplicate titles get a number appended so that no key is lost.
+fn unique_keys<C: TableColumn>(columns: &[C]) -> Vec<String> {
+    let mut keys: Vec<String> = Vec::with_capacity(columns.len());
+
+    for column in columns {
+        let title = column.title();
+        let mut key = title.clone();
+        let mut number = 1;
+
+        while keys.contains(&key) {
+            number += 1;
+            key = format!("{title} ({number})");
+        }
+
+        keys.push(key);
+    }
+
+    keys
+}
+
+/// Writes the object manually to keep the keys in the order of the columns.
+fn displayed_values_json<T: TableRow>(
+    row: &T,
+    columns: &[T::ColumnName],
+    keys: &[String],
+) -> String {
+    let fields = columns
+        .iter()
+        .zip(keys)
+        .map(|(column, key)| {
+            format!(
+                "{}:{}",
+                serde_json::Value::String(key.clone()),
+                serde_json::Value::String(row.cell_text(*column))
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+
+    format!("{{{fields}}}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use std::ops::Range;
+
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    enum BookColumn {
+        Title,
+        Price,
+    }
+
+    impl TableColumn for BookColumn {
+        fn columns() -> Vec<Self> {
+            vec![BookColumn::Title, BookColumn::Price]
+        }
+
+        fn title(&self) -> String {
+            format!("{self:?}")
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq, Serialize)]
+    struct Book {
+        title: &'static str,
+        price: f64,
+    }
+
+    impl TableRow for Book {
+        type ColumnName = BookColumn;
+
+        fn cell_text(&self, column: BookColumn) -> String {
+            match column {
+                BookColumn::Title => self.title.to_string(),
+                BookColumn::Price => format!("{:.2}", self.price),
+            }
+        }
+    }
+
+    fn books() -> Vec<Book> {
+        vec![
+            Book {
+                title: "Dune",
+                price: 9.5,
+            },
+            Book {
+                title: "Emma \"1815\"\n",
+                price: 4.0,
+            },
+        ]
+    }
+
+    struct Books(Vec<Book>);
+
+    #[async_trait(?Send)]
+    impl TableDataProvider<Book> for Books {
+        type ColumnName = BookColumn;
+
+        async fn get_rows(&self, range: Range<usize>) -> Vec<Book> {
+            crate::get_rows_in_range(&self.0, range)
+        }
+    }
+
+    #[test]
+    fn array_of_raw_values() {
+        let json = JsonExport::new().write(&books()).unwrap();
+
+        assert_eq!(
+            json,
+            r#"[{"title":"Dune","price":9.5},{"title":"Emma \"1815\"\n","price":4.0}]"#
+        );
+        assert!(serde_json::from_str::<serde_json::Value>(&json).is_ok());
+    }
+
+    #[test]
+    fn empty_array() {
+        assert_eq!(JsonExport::<Book>::new().write(&[]).unwrap(), "[]");
+        assert_eq!(
+            JsonExport::<Book>::new()
+                .with_format(JsonFormat::Ndjson)
+                .write(&[])
+                .unwrap(),
+            ""
+        );
+    }
+
+    #[test]
+    fn ndjson_writes_one_line_per_row() {
+        let json = JsonExport::new()
+            .with_format(JsonFormat::Ndjson)
+            .write(&books())
+            .unwrap();
+
+        let lines = json.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0], r#"{"title":"Dune","price":9.5}"#);
+        assert!(json.ends_with('\n'));
+
+        // line breaks inside of values are escaped so they don't split a row
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(lines[1]).unwrap()["title"],
+            "Emma \"1815\"\n"
+        );
+    }
+
+    #[test]
+    fn displayed_values_use_the_titles_and_cell_texts() {
+        let json = JsonExport::new()
+            .with_values(JsonValues::Displayed)
+            .write(&books())
+            .unwrap();
+
+        assert_eq!(
+            json,
+            r#"[{"Title":"Dune","Price":"9.50"},{"Title":"Emma \"1815\"\n","Price":"4.00"}]"#
+        );
+    }
+
+    #[test]
+    fn only_rows_filters_the_rows() {
+        let json = JsonExport::new()
+            .only_rows(|book: &Book| book.price > 5.0)
+            .write(&books())
+            .unwrap();
+
+        assert_eq!(json, r#"[{"title":"Dune","price":9.5}]"#);
+
+        let json = JsonExport::new()
+            .only_rows(|_: &Book| false)
+            .write(&books())
+            .unwrap();
+
+        assert_eq!(json, "[]");
+    }
+
+    #[test]
+    fn duplicate_titles_get_unique_keys() {
+        #[derive(Copy, Clone)]
+        struct Same(u8);
+
+        impl TableColumn for Same {
+            fn columns() -> Vec<Self> {
+                vec![Same(0), Same(1), Same(2), Same(3)]
+            }
+
+            fn title(&self) -> String {
+                match self.0 {
+                    2 => "Name (2)".to_string(),
+                    _ => "Name".to_string(),
+                }
+            }
+        }
+
+        assert_eq!(
+            unique_keys(&Same::columns()),
+            vec!["Name", "Name (2)", "Name (2) (2)", "Name (3)"]
+        );
+    }
+
+    #[test]
+    fn export_loads_all_chunks() {
+        let provider = Books(books());
+
+        let json = futures::executor::block_on(
+            JsonExport::new()
+                .with_chunk_size(1)
+                .with_format(JsonFormat::Ndjson)
+                .only_rows(|book: &Book| book.title == "Dune")
+                .export(&provider),
+        )
+        .unwrap();
+
+        assert_eq!(json, "{\"title\":\"Dune\",\"price\":9.5}\n");
+    }
+
+    #[test]
+    fn clone_doesnt_require_clonable_rows() {
+        struct NotClone;
+
+        let export = JsonExport::<NotClone> {
+            format: JsonFormat::Ndjson,
+            values: JsonValues::Displayed,
+            chunk_size: 7,
+            row_filter: None,
+        };
+
+        assert_eq!(export.clone().chunk_size, 7);
+    }
+}
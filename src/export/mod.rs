@@ -0,0 +1,119 @@
+mod csv;
+mod download;
+mod json;
+#[cfg(feature = "xlsx")]
+mod xlsx;
+
+pub use self::csv::*;
+pub use download::*;
+pub use json::*;
+#[cfg(feature = "xlsx")]
+pub use xlsx::*;
+
+use crate::TableDataProvider;
+use core::fmt::Debug;
+
+/// The number of rows that are loaded at once when exporting from a data provider.
+pub const DEFAULT_EXPORT_CHUNK_SIZE: usize = 500;
+
+/// Loads all rows from `provider` in chunks of `chunk_size` rows and calls `on_chunk` for each chunk.
+/// Stops when the provider returns less rows than requested or no rows at all.
+pub(crate) async fn for_each_chunk<T, P>(
+    provider: &P,
+    chunk_size: usize,
+    mut on_chunk: impl FnMut(Vec<T>),
+) -> Result<(), String>
+where
+    T: Debug + PartialEq,
+    P: TableDataProvider<T> + ?Sized,
+{
+    let chunk_size = chunk_size.max(1);
+    let mut start = 0;
+
+    loop {
+        let rows = provider.try_get_rows(start..start + chunk_size).await?;
+        let row_count = rows.len();
+
+        if row_count == 0 {
+            return Ok(());
+        }
+
+        on_chunk(rows);
+
+        if row_count < chunk_size {
+            return Ok(());
+        }
+
+        start += chunk_size;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_rows_in_range;
+    use async_trait::async_trait;
+    use std::cell::RefCell;
+    use std::ops::Range;
+
+    struct Numbers {
+        rows: Vec<u32>,
+        requests: RefCell<Vec<Range<usize>>>,
+    }
+
+    impl Numbers {
+        fn new(count: u32) -> Self {
+            Self {
+                rows: (0..count).collect(),
+                requests: RefCell::new(vec![]),
+            }
+        }
+    }
+
+    #[async_trait(?Send)]
+    impl TableDataProvider<u32> for Numbers {
+        type ColumnName = ();
+
+        async fn get_rows(&self, range: Range<usize>) -> Vec<u32> {
+            self.requests.borrow_mut().push(range.clone());
+            get_rows_in_range(&self.rows, range)
+        }
+    }
+
+    fn chunks(provider: &Numbers, chunk_size: usize) -> Vec<Vec<u32>> {
+        let mut chunks = vec![];
+
+        futures::executor::block_on(for_each_chunk(provider, chunk_size, |chunk| {
+            chunks.push(chunk)
+        }))
+        .unwrap();
+
+        chunks
+    }
+
+    #[test]
+    fn exact_multiple_of_the_chunk_size_doesnt_repeat_the_last_row() {
+        let provider = Numbers::new(4);
+
+        assert_eq!(chunks(&provider, 2), vec![vec![0, 1], vec![2, 3]]);
+        assert_eq!(*provider.requests.borrow(), vec![0..2, 2..4, 4..6]);
+    }
+
+    #[test]
+    fn last_chunk_can_be_smaller() {
+        let provider = Numbers::new(5);
+
+        assert_eq!(chunks(&provider, 2), vec![vec![0, 1], vec![2, 3], vec![4]]);
+        assert_eq!(*provider.requests.borrow(), vec![0..2, 2..4, 4..6]);
+    }
+
+    #[test]
+    fn no_rows_means_no_chunks() {
+        assert!(chunks(&Numbers::new(0), 2).is_empty());
+    }
+
+    #[test]
+    fn chunk_size_of_zero_loads_one_row_at_a_time() {
+        assert_eq!(chunks(&Numbers::new(2), 0), vec![vec![0], vec![1]]);
+    }
+}
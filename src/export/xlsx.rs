@@ -0,0 +1,499 @@
+#![doc(cfg(feature = "xlsx"))]
+
+use super::{for_each_chunk, DEFAULT_EXPORT_CHUNK_SIZE};
+use crate::{CellValue, TableColumn, TableDataProvider, TableRow};
+use core::fmt::Debug;
+use rust_xlsxwriter::{Format, Workbook, Worksheet, XlsxError};
+
+/// The MIME type of `.xlsx` files. Use it with [`download_bytes`](crate::download_bytes).
+pub const XLSX_MIME_TYPE: &str =
+    "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet";
+
+/// Writes `rows` to an `.xlsx` workbook with a single worksheet and returns the file content.
+///
+/// The first row contains the column titles and is frozen. The cells are typed according to
+/// [`TableRow::cell_value`]: numbers become numeric cells that use [`TableColumn::precision`] as number format
+/// and dates and times become Excel dates formatted with [`TableColumn::format_string`]. Dates before 1900 can't be
+/// displayed by Excel and are written as text like everything else. Only the columns returned by [`TableColumn::columns`] are written.
+///
+/// This is only available when the **crate feature `xlsx`** is enabled.
+pub fn write_xlsx<T: TableRow>(rows: &[T]) -> Result<Vec<u8>, String> {
+    let mut writer = XlsxWriter::<T::ColumnName>::new().map_err(|err| err.to_string())?;
+    writer.write_rows(rows).map_err(|err| err.to_string())?;
+    writer.finish().map_err(|err| err.to_string())
+}
+
+/// Loads all rows from `provider` and writes them to an `.xlsx` workbook. See [`write_xlsx`] for the format.
+///
+/// The rows are loaded in chunks through [`TableDataProvider::try_get_rows`], so the current sorting
+/// and filters of the provider apply.
+///
+/// This is only available when the **crate feature `xlsx`** is enabled.
+///
+/// ```
+/// # use leptos_struct_table::*;
+/// # #[derive(Debug, PartialEq)] struct Book;
+/// # #[derive(Copy, Clone)] enum BookColumnName {}
+/// # impl TableColumn for BookColumnName { fn columns() -> Vec<Self> { vec![] } fn title(&self) -> String { unreachable!() } }
+/// # impl TableRow for Book { type ColumnName = BookColumnName; fn cell_text(&self, _: BookColumnName) -> String { unreachable!() } }
+/// # async fn export<P: TableDataProvider<Book>>(provider: P) -> Result<(), String> {
+/// let xlsx = export_xlsx(&provider).await?;
+///
+/// download_bytes("books.xlsx", XLSX_MIME_TYPE, &xlsx).map_err(|_| "Download failed".to_string())?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn export_xlsx<T, P>(provider: &P) -> Result<Vec<u8>, String>
+where
+    T: TableRow + Debug + PartialEq,
+    P: TableDataProvider<T> + ?Sized,
+{
+    let mut writer = XlsxWriter::<T::ColumnName>::new().map_err(|err| err.to_string())?;
+    let mut result = Ok(());
+
+    for_each_chunk(provider, DEFAULT_EXPORT_CHUNK_SIZE, |rows| {
+        if result.is_ok() {
+            result = writer.write_rows(&rows);
+        }
+    })
+    .await?;
+    result.map_err(|err| err.to_string())?;
+
+    writer.finish().map_err(|err| err.to_string())
+}
+
+struct XlsxWriter<C: TableColumn> {
+    worksheet: Worksheet,
+    columns: Vec<C>,
+    formats: Vec<ColumnFormats>,
+    next_row: u32,
+}
+
+/// The number formats of a column for the different types of values.
+struct ColumnFormats {
+    number: Format,
+    #[cfg(feature = "chrono")]
+    date: Format,
+    #[cfg(feature = "chrono")]
+    date_time: Format,
+    #[cfg(feature = "chrono")]
+    time: Format,
+}
+
+impl ColumnFormats {
+    #[allow(unused_variables)]
+    fn new(precision: Option<usize>, format_string: Option<&str>) -> Self {
+        let number = match precision {
+            Some(0) => Format::new().set_num_format("0"),
+            Some(precision) => Format::new().set_num_format(format!("0.{}", "0".repeat(precision))),
+            None => Format::new(),
+        };
+
+        #[cfg(feature = "chrono")]
+        let excel_format = format_string.and_then(excel_date_format);
+
+        Self {
+            number,
+            #[cfg(feature = "chrono")]
+            date: Format::new().set_num_format(excel_format.as_deref().unwrap_or("yyyy-mm-dd")),
+            #[cfg(feature = "chrono")]
+            date_time: Format::new()
+                .set_num_format(excel_format.as_deref().unwrap_or("yyyy-mm-dd hh:mm:ss")),
+            #[cfg(feature = "chrono")]
+            time: Format::new().set_num_format(excel_format.as_deref().unwrap_or("hh:mm:ss")),
+        }
+    }
+}
+
+impl<C: TableColumn> XlsxWriter<C> {
+    fn new() -> Result<Self, XlsxError> {
+        let columns = C::columns();
+
+        let mut worksheet = Worksheet::new();
+        let header_format = Format::new().set_bold();
+
+        for (index, column) in columns.iter().enumerate() {
+            worksheet.write_string_with_format(0, index as u16, &column.title(), &header_format)?;
+        }
+        worksheet.set_freeze_panes(1, 0)?;
+
+        let formats = columns
+            .iter()
+            .map(|column| ColumnFormats::new(column.precision(), column.format_string()))
+            .collect();
+
+        Ok(Self {
+            worksheet,
+            columns,
+            formats,
+            next_row: 1,
+        })
+    }
+
+    fn write_rows<T: TableRow<ColumnName = C>>(&mut self, rows: &[T]) -> Result<(), XlsxError> {
+        for row in rows {
+            for (index, column) in self.columns.iter().enumerate() {
+                self.write_cell(index as u16, row.cell_value(*column))?;
+            }
+
+            self.next_row += 1;
+        }
+
+        Ok(())
+    }
+
+    fn write_cell(&mut self, col: u16, value: CellValue) -> Result<(), XlsxError> {
+        let row = self.next_row;
+        let formats = &self.formats[col as usize];
+
+        match value {
+            CellValue::Empty => {}
+            CellValue::Bool(value) => {
+                self.worksheet.write_boolean(row, col, value)?;
+            }
+            CellValue::Int(value) => {
+                self.worksheet
+                    .write_number_with_format(row, col, value as f64, &formats.number)?;
+            }
+            CellValue::Float(value) => {
+                self.worksheet
+                    .write_number_with_format(row, col, value, &formats.number)?;
+            }
+            CellValue::Text(value) => {
+                self.worksheet.write_string(row, col, &value)?;
+            }
+            #[cfg(feature = "chrono")]
+            CellValue::Date(value) => match excel_serial_date(value) {
+                Some(serial) => {
+                    self.worksheet
+                        .write_number_with_format(row, col, serial, &formats.date)?;
+                }
+                None => {
+                    self.worksheet.write_string(row, col, &value.to_string())?;
+                }
+            },
+            #[cfg(feature = "chrono")]
+            CellValue::DateTime(value) => match excel_serial_date(value.date()) {
+                Some(serial) => {
+                    self.worksheet.write_number_with_format(
+                        row,
+                        col,
+                        serial + excel_serial_time(value.time()),
+                        &formats.date_time,
+                    )?;
+                }
+                None => {
+                    self.worksheet.write_string(row, col, &value.to_string())?;
+                }
+            },
+            #[cfg(feature = "chrono")]
+            CellValue::Time(value) => {
+                self.worksheet.write_number_with_format(
+                    row,
+                    col,
+                    excel_serial_time(value),
+                    &formats.time,
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Vec<u8>, XlsxError> {
+        let mut workbook = Workbook::new();
+        workbook.push_worksheet(self.worksheet);
+        workbook.save_to_buffer()
+    }
+}
+
+/// Excel stores dates as the number of days since 1900-01-00, so 1900-01-01 is `1`. For compatibility with Lotus 1-2-3
+/// Excel treats 1900 as a leap year, so `60` is the non-existent 1900-02-29 and all later dates are one day further.
+/// Returns `None` for dates before 1900 because Excel can't display them.
+#[cfg(feature = "chrono")]
+fn excel_serial_date(date: chrono::NaiveDate) -> Option<f64> {
+    let first_day = chrono::NaiveDate::from_ymd_opt(1900, 1, 1).expect("valid date");
+    let leap_day = chrono::NaiveDate::from_ymd_opt(1900, 3, 1).expect("valid date");
+
+    if date < first_day {
+        return None;
+    }
+
+    let days = (date - first_day).num_days() + 1;
+
+    Some(if date >= leap_day { days + 1 } else { days } as f64)
+}
+
+/// Excel stores times as the fraction of the day.
+#[cfg(feature = "chrono")]
+fn excel_serial_time(time: chrono::NaiveTime) -> f64 {
+    use chrono::Timelike;
+
+    (time.num_seconds_from_midnight() as f64 + time.nanosecond() as f64 / 1e9) / 86400.0
+}
+
+/// Translates a chrono format string into an Excel number format.
+/// Returns `None` if it contains specifiers that Excel can't display.
+#[cfg(feature = "chrono")]
+fn excel_date_format(format_string: &str) -> Option<String> {
+    let mut excel_format = String::new();
+    let mut chars = format_string.chars();
+
+    while let Some(char) = chars.next() {
+        if char != '%' {
+            if char.is_ascii_alphanumeric() || char == '\\' || char == '"' {
+                excel_format.push('\\');
+            }
+            excel_format.push(char);
+            continue;
+        }
+
+        let specifier = match chars.next()? {
+            'Y' => "yyyy",
+            'y' => "yy",
+            'm' => "mm",
+            'b' | 'h' => "mmm",
+            'B' => "mmmm",
+            'd' => "dd",
+            'e' => "d",
+            'a' => "ddd",
+            'A' => "dddd",
+            'H' => "hh",
+            'I' => "hh",
+            'M' => "mm",
+            'S' => "ss",
+            'p' => "AM/PM",
+            'F' => "yyyy-mm-dd",
+            'T' => "hh:mm:ss",
+            'R' => "hh:mm",
+            '%' => "\\%",
+            _ => return None,
+        };
+        excel_format.push_str(specifier);
+    }
+
+    Some(excel_format)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    enum ItemColumn {
+        Name,
+        Price,
+        Count,
+        InStock,
+        Note,
+    }
+
+    impl TableColumn for ItemColumn {
+        fn columns() -> Vec<Self> {
+            vec![
+                ItemColumn::Name,
+                ItemColumn::Price,
+                ItemColumn::Count,
+                ItemColumn::InStock,
+                ItemColumn::Note,
+            ]
+        }
+
+        fn title(&self) -> String {
+            format!("{self:?}")
+        }
+
+        fn precision(&self) -> Option<usize> {
+            match self {
+                ItemColumn::Price => Some(2),
+                _ => None,
+            }
+        }
+    }
+
+    struct Item;
+
+    impl TableRow for Item {
+        type ColumnName = ItemColumn;
+
+        fn cell_text(&self, column: ItemColumn) -> String {
+            self.cell_value(column).to_string()
+        }
+
+        fn cell_value(&self, column: ItemColumn) -> CellValue {
+            match column {
+                ItemColumn::Name => "Apple".into(),
+                ItemColumn::Price => 0.5.into(),
+                ItemColumn::Count => 12.into(),
+                ItemColumn::InStock => true.into(),
+                ItemColumn::Note => CellValue::Empty,
+            }
+        }
+    }
+
+    fn read_part(xlsx: &[u8], name: &str) -> String {
+        let mut archive = zip::ZipArchive::new(std::io::Cursor::new(xlsx)).unwrap();
+        let mut content = String::new();
+        archive
+            .by_name(name)
+            .unwrap()
+            .read_to_string(&mut content)
+            .unwrap();
+        content
+    }
+
+    /// The XML of the cell `reference` like `B2`.
+    fn cell<'a>(sheet: &'a str, reference: &str) -> Option<&'a str> {
+        let start = sheet.find(&format!("<c r=\"{reference}\""))?;
+        let end = start + sheet[start..].find("</c>")?;
+        Some(&sheet[start..end])
+    }
+
+    #[test]
+    fn write_xlsx_writes_a_frozen_header_and_typed_cells() {
+        let xlsx = write_xlsx(&[Item]).unwrap();
+
+        let sheet = read_part(&xlsx, "xl/worksheets/sheet1.xml");
+        let strings = read_part(&xlsx, "xl/sharedStrings.xml");
+        let styles = read_part(&xlsx, "xl/styles.xml");
+
+        assert!(sheet.contains("ySplit=\"1\""));
+        assert!(sheet.contains("state=\"frozen\""));
+
+        for title in ["Name", "Price", "Count", "InStock", "Note"] {
+            assert!(strings.contains(&format!("<t>{title}</t>")), "{title}");
+        }
+        assert!(cell(&sheet, "A1").unwrap().contains("t=\"s\""));
+
+        assert!(cell(&sheet, "A2").unwrap().contains("t=\"s\""));
+        assert!(strings.contains("<t>Apple</t>"));
+
+        let price = cell(&sheet, "B2").unwrap();
+        assert!(!price.contains("t=\""));
+        assert!(price.contains("<v>0.5</v>"));
+        assert!(styles.contains("formatCode=\"0.00\""));
+
+        let count = cell(&sheet, "C2").unwrap();
+        assert!(!count.contains("t=\""));
+        assert!(count.contains("<v>12</v>"));
+
+        assert!(cell(&sheet, "D2").unwrap().contains("t=\"b\""));
+        assert_eq!(cell(&sheet, "E2"), None);
+        assert_eq!(cell(&sheet, "A3"), None);
+    }
+
+    #[test]
+    fn export_xlsx_writes_the_rows_of_the_provider() {
+        use async_trait::async_trait;
+        use std::ops::Range;
+
+        #[derive(Debug, PartialEq)]
+        struct Row(u32);
+
+        impl TableRow for Row {
+            type ColumnName = ItemColumn;
+
+            fn cell_text(&self, _: ItemColumn) -> String {
+                self.0.to_string()
+            }
+
+            fn cell_value(&self, _: ItemColumn) -> CellValue {
+                self.0.into()
+            }
+        }
+
+        struct Rows;
+
+        #[async_trait(?Send)]
+        impl TableDataProvider<Row> for Rows {
+            type ColumnName = ItemColumn;
+
+            async fn get_rows(&self, range: Range<usize>) -> Vec<Row> {
+                range
+                    .take_while(|index| *index < 3)
+                    .map(|index| Row(index as u32))
+                    .collect()
+            }
+        }
+
+        let xlsx = futures::executor::block_on(export_xlsx(&Rows)).unwrap();
+        let sheet = read_part(&xlsx, "xl/worksheets/sheet1.xml");
+
+        assert!(cell(&sheet, "A4").unwrap().contains("<v>2</v>"));
+        assert_eq!(cell(&sheet, "A5"), None);
+    }
+}
+
+#[cfg(all(test, feature = "chrono"))]
+mod date_tests {
+    use super::*;
+    use chrono::{NaiveDate, NaiveTime};
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn serial_dates_count_days_since_the_excel_epoch() {
+        assert_eq!(excel_serial_date(date(1900, 1, 1)), Some(1.0));
+        assert_eq!(excel_serial_date(date(1900, 2, 28)), Some(59.0));
+        assert_eq!(excel_serial_date(date(1900, 3, 1)), Some(61.0));
+        assert_eq!(excel_serial_date(date(1970, 1, 1)), Some(25569.0));
+        assert_eq!(excel_serial_date(date(2023, 4, 10)), Some(45026.0));
+    }
+
+    #[test]
+    fn serial_dates_before_1900_are_not_supported() {
+        assert_eq!(excel_serial_date(date(1899, 12, 31)), None);
+        assert_eq!(excel_serial_date(date(1066, 10, 14)), None);
+    }
+
+    #[test]
+    fn serial_times_are_fractions_of_the_day() {
+        assert_eq!(excel_serial_time(NaiveTime::MIN), 0.0);
+        assert_eq!(
+            excel_serial_time(NaiveTime::from_hms_opt(12, 0, 0).unwrap()),
+            0.5
+        );
+        assert_eq!(
+            excel_serial_time(NaiveTime::from_hms_opt(18, 0, 0).unwrap()),
+            0.75
+        );
+    }
+
+    #[test]
+    fn date_format_specifiers_are_translated() {
+        assert_eq!(excel_date_format("%d.%m.%Y").as_deref(), Some("dd.mm.yyyy"));
+        assert_eq!(
+            excel_date_format("%Y-%m-%d %H:%M:%S").as_deref(),
+            Some("yyyy-mm-dd hh:mm:ss")
+        );
+        assert_eq!(excel_date_format("%e %B %y").as_deref(), Some("d mmmm yy"));
+        assert_eq!(
+            excel_date_format("%a, %b %d").as_deref(),
+            Some("ddd, mmm dd")
+        );
+        assert_eq!(
+            excel_date_format("%I:%M %p").as_deref(),
+            Some("hh:mm AM/PM")
+        );
+        assert_eq!(
+            excel_date_format("%F %T").as_deref(),
+            Some("yyyy-mm-dd hh:mm:ss")
+        );
+    }
+
+    #[test]
+    fn date_format_literals_are_escaped() {
+        assert_eq!(excel_date_format("at %R").as_deref(), Some("\\a\\t hh:mm"));
+        assert_eq!(excel_date_format("%d%%").as_deref(), Some("dd\\%"));
+        assert_eq!(excel_date_format("\"%Y\"").as_deref(), Some("\\\"yyyy\\\""));
+    }
+
+    #[test]
+    fn unsupported_date_formats_are_rejected() {
+        assert_eq!(excel_date_format("%Y week %W"), None);
+        assert_eq!(excel_date_format("%Y%"), None);
+    }
+}
@@ -0,0 +1,249 @@
+use leptos::ev::KeyboardEvent;
+use leptos::wasm_bindgen::JsCast;
+use leptos::*;
+
+/// The position of the focused cell of a table. Used for keyboard navigation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FocusedCell {
+    /// A header cell. `column` is the index of the column.
+    Head { column: usize },
+    /// A body cell. `row` is the index of the row and `column` the index of the column.
+    Body { row: usize, column: usize },
+}
+
+impl Default for FocusedCell {
+    fn default() -> Self {
+        Self::Head { column: 0 }
+    }
+}
+
+impl FocusedCell {
+    /// The index of the column of the cell.
+    pub fn column(&self) -> usize {
+        match self {
+            Self::Head { column } | Self::Body { column, .. } => *column,
+        }
+    }
+
+    /// The index of the row of the cell. `None` for header cells.
+    pub fn row(&self) -> Option<usize> {
+        match self {
+            Self::Head { .. } => None,
+            Self::Body { row, .. } => Some(*row),
+        }
+    }
+
+    /// Returns the cell that is focused after pressing `key` on this cell or `None` if the key doesn't move the focus.
+    ///
+    /// - Arrow keys move to the neighbouring cell. Up from the first row moves into the header.
+    /// - `Home`/`End` move to the first/last cell of the row. With `ctrl` pressed to the first/last cell of the table.
+    /// - `PageUp`/`PageDown` move `page_size` rows up/down.
+    ///
+    /// `row_count` is the number of body rows and `column_count` the number of columns.
+    pub fn navigate(
+        self,
+        key: &str,
+        ctrl: bool,
+        row_count: usize,
+        column_count: usize,
+        page_size: usize,
+    ) -> Option<Self> {
+        if column_count == 0 {
+            return None;
+        }
+
+        let last_column = column_count - 1;
+        let last_row = row_count.checked_sub(1);
+        let column = self.column().min(last_column);
+
+        // header cells have row `None` and come before the first body row
+        let row = self.row();
+        let with_row = |row: Option<usize>, column: usize| match row {
+            Some(row) => Self::Body { row, column },
+            None => Self::Head { column },
+        };
+        let move_down = |rows: usize| match (row, last_row) {
+            (_, None) => row,
+            (None, Some(last_row)) => Some((rows - 1).min(last_row)),
+            (Some(row), Some(last_row)) => Some((row + rows).min(last_row)),
+        };
+        let move_up = |rows: usize| match row {
+            Some(row) if row >= rows => Some(row - rows),
+            _ => None,
+        };
+
+        let focused = match key {
+            "ArrowLeft" => with_row(row, column.saturating_sub(1)),
+            "ArrowRight" => with_row(row, (column + 1).min(last_column)),
+            "ArrowUp" => with_row(move_up(1), column),
+            "ArrowDown" => with_row(move_down(1), column),
+            "PageUp" => with_row(move_up(page_size.max(1)), column),
+            "PageDown" => with_row(move_down(page_size.max(1)), column),
+            "Home" if ctrl => Self::Head { column: 0 },
+            "End" if ctrl => with_row(last_row, last_column),
+            "Home" => with_row(row, 0),
+            "End" => with_row(row, last_column),
+            _ => return None,
+        };
+
+        Some(focused)
+    }
+}
+
+/// Moves the focused cell according to a `keydown` event. Returns `true` and prevents the default
+/// behaviour, like scrolling, if the focus was moved. See [`FocusedCell::navigate`] for the keys.
+///
+/// [`DynamicTable`](crate::DynamicTable) calls this from the `keydown` handler of the table. Do the same in your own
+/// table components.
+/// Activating the focused cell with Enter or Space is handled by the row and header renderers.
+///
+/// Keys pressed inside inputs, text areas, selects and editable elements are ignored so that they keep moving the
+/// text cursor.
+pub fn handle_navigation_key(
+    event: &KeyboardEvent,
+    focused_cell: RwSignal<Option<FocusedCell>>,
+    row_count: usize,
+    column_count: usize,
+    page_size: usize,
+) -> bool {
+    if is_editable_target(event) {
+        return false;
+    }
+
+    let current = focused_cell.get_untracked().unwrap_or_default();
+
+    let next = current.navigate(
+        &event.key(),
+        event.ctrl_key() || event.meta_key(),
+        row_count,
+        column_count,
+        page_size,
+    );
+
+    match next {
+        Some(next) => {
+            event.prevent_default();
+            focused_cell.set(Some(next));
+            true
+        }
+        None => false,
+    }
+}
+
+/// Whether the target of the event is an element that uses the arrow keys itself.
+fn is_editable_target(event: &KeyboardEvent) -> bool {
+    let Some(element) = event
+        .target()
+        .and_then(|target| target.dyn_into::<web_sys::HtmlElement>().ok())
+    else {
+        return false;
+    };
+
+    matches!(element.tag_name().as_str(), "INPUT" | "TEXTAREA" | "SELECT")
+        || element.is_content_editable()
+}
+
+/// Implements a roving `tabindex` for a cell: Only the focused cell is reachable with the Tab key.
+///
+/// Returns the value for the `tabindex` attribute. `focused` is `None` if keyboard navigation is not used,
+/// then no `tabindex` is set. `focus` is called to move the browser focus to the cell when it becomes focused.
+pub fn create_roving_tabindex(
+    focused: Option<Signal<bool>>,
+    focus: impl Fn() + 'static,
+) -> impl Fn() -> Option<&'static str> + Copy {
+    if let Some(focused) = focused {
+        create_effect(move |was_focused: Option<bool>| {
+            let is_focused = focused.get();
+
+            // don't steal the focus when the table is rendered for the first time
+            if is_focused && was_focused == Some(false) {
+                focus();
+            }
+
+            is_focused
+        });
+    }
+
+    move || focused.map(|focused| if focused.get() { "0" } else { "-1" })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn head(column: usize) -> FocusedCell {
+        FocusedCell::Head { column }
+    }
+
+    fn body(row: usize, column: usize) -> FocusedCell {
+        FocusedCell::Body { row, column }
+    }
+
+    fn navigate(cell: FocusedCell, key: &str) -> Option<FocusedCell> {
+        cell.navigate(key, false, 20, 3, 5)
+    }
+
+    #[test]
+    fn arrow_keys_move_to_the_neighbouring_cell() {
+        assert_eq!(navigate(body(4, 1), "ArrowLeft"), Some(body(4, 0)));
+        assert_eq!(navigate(body(4, 1), "ArrowRight"), Some(body(4, 2)));
+        assert_eq!(navigate(body(4, 1), "ArrowUp"), Some(body(3, 1)));
+        assert_eq!(navigate(body(4, 1), "ArrowDown"), Some(body(5, 1)));
+    }
+
+    #[test]
+    fn arrow_keys_stop_at_the_edges() {
+        assert_eq!(navigate(body(4, 0), "ArrowLeft"), Some(body(4, 0)));
+        assert_eq!(navigate(body(4, 2), "ArrowRight"), Some(body(4, 2)));
+        assert_eq!(navigate(body(19, 1), "ArrowDown"), Some(body(19, 1)));
+        assert_eq!(navigate(head(1), "ArrowUp"), Some(head(1)));
+    }
+
+    #[test]
+    fn header_and_first_row_are_connected() {
+        assert_eq!(navigate(body(0, 2), "ArrowUp"), Some(head(2)));
+        assert_eq!(navigate(head(2), "ArrowDown"), Some(body(0, 2)));
+        assert_eq!(navigate(head(0), "ArrowRight"), Some(head(1)));
+    }
+
+    #[test]
+    fn page_keys_move_by_page_size() {
+        assert_eq!(navigate(body(7, 1), "PageUp"), Some(body(2, 1)));
+        assert_eq!(navigate(body(3, 1), "PageUp"), Some(head(1)));
+        assert_eq!(navigate(body(7, 1), "PageDown"), Some(body(12, 1)));
+        assert_eq!(navigate(body(17, 1), "PageDown"), Some(body(19, 1)));
+        assert_eq!(navigate(head(1), "PageDown"), Some(body(4, 1)));
+    }
+
+    #[test]
+    fn home_and_end_move_within_the_row_or_the_table() {
+        assert_eq!(navigate(body(4, 1), "Home"), Some(body(4, 0)));
+        assert_eq!(navigate(body(4, 1), "End"), Some(body(4, 2)));
+        assert_eq!(body(4, 1).navigate("Home", true, 20, 3, 5), Some(head(0)));
+        assert_eq!(
+            body(4, 1).navigate("End", true, 20, 3, 5),
+            Some(body(19, 2))
+        );
+    }
+
+    #[test]
+    fn other_keys_dont_move_the_focus() {
+        assert_eq!(navigate(body(4, 1), "Enter"), None);
+        assert_eq!(navigate(body(4, 1), "a"), None);
+    }
+
+    #[test]
+    fn empty_tables_keep_the_focus_in_the_header() {
+        assert_eq!(head(1).navigate("ArrowDown", false, 0, 3, 5), Some(head(1)));
+        assert_eq!(head(1).navigate("End", true, 0, 3, 5), Some(head(2)));
+        assert_eq!(head(0).navigate("ArrowDown", false, 0, 0, 5), None);
+    }
+
+    #[test]
+    fn columns_that_dont_exist_anymore_are_clamped() {
+        assert_eq!(
+            body(4, 7).navigate("ArrowDown", false, 20, 3, 5),
+            Some(body(5, 2))
+        );
+    }
+}
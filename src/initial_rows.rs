@@ -0,0 +1,36 @@
+use crate::TableDataProvider;
+use core::fmt::Debug;
+use leptos::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::ops::Range;
+
+/// Creates a resource that loads the rows in `range` from the data provider in `items` through
+/// [`TableDataProvider::try_get_rows`].
+///
+/// During server side rendering the resource is resolved on the server and the loaded rows are serialized
+/// into the HTML. When the page is hydrated, the rows are deserialized from there instead of calling
+/// the data provider a second time. This is why the rows have to implement `Serialize` and `Deserialize`,
+/// which structs that derive `TableComponent` do anyway.
+///
+/// The component generated by deriving `TableComponent` only loads rows in the browser. Use this to render
+/// the first rows of your own table component on the server. Read the resource inside of a `<Suspense/>` or `<Transition/>` so the server waits for the rows
+/// before it sends the table.
+pub fn create_initial_rows_resource<T, P>(
+    items: RwSignal<P>,
+    range: Range<usize>,
+) -> Resource<(), Result<Vec<T>, String>>
+where
+    T: Debug + PartialEq + Serialize + DeserializeOwned + 'static,
+    P: TableDataProvider<T> + Clone + 'static,
+{
+    create_resource(
+        || (),
+        move |_| {
+            let provider = items.get_untracked();
+            let range = range.clone();
+
+            async move { provider.try_get_rows(range).await }
+        },
+    )
+}
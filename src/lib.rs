@@ -209,6 +209,112 @@ pub struct TemperatureMeasurement {
 //! The `getter` attribute should be used on a field that actually exists on the struct but whose
 //! value you want to modify before it's rendered.
 //!
+//! # Sorting
+//!
+//! If `sortable` is set on the struct, clicking a header sorts the table by this column. To start with a sorting, pass
+//! it to the data provider, e.g. with [`ServerFnDataProvider::with_sorting`].
+//!
+//! [`DynamicTable`] updates its sorting with [`toggle_column_sort_with`]. Every click cycles through the initial direction
+//! of the column, see [`DynamicColumn::with_initial_sort`], the opposite direction and unsorted. Holding Shift adds the
+//! column to the sorting instead of replacing it. With its `sort_options` prop you can allow only a single sorted column
+//! ([`SortMode::Single`]) or limit the number of sorted columns ([`SortOptions::max_columns`]). You can use the same
+//! function in your own table components.
+//!
+//! The data provider receives the sorting through [`TableDataProvider::set_sorting`].
+//! The `TableDataProvider` implementation for `Vec<T>` that is generated by deriving `TableComponent` doesn't sort the
+//! rows yet, so clicking a header only changes the sort indicators. To sort rows in memory, implement [`TableRow`] for your
+//! struct and call [`sort_rows`] in the `set_sorting` of your own data provider. Keep a copy of the rows in their original
+//! order if they should return to it when the sorting is removed.
+//!
+//! [`sort_rows`] compares two rows column by column in the order of the sort priority with [`compare_rows`], just like
+//! [`TableQuery::apply_rows`] on the server. The sort is stable. Columns that aren't [`sortable`](TableColumn::sortable)
+//! are ignored and empty values are placed according to [`TableColumn::none_position`], regardless of the sort direction.
+//!
+//! By default the displayed values are compared, so `getter`s and [`FieldGetter`]s are sorted by the values they return.
+//! For values where this doesn't give the right order, like version strings or placeholders like "N/A", you can
+//! override [`TableRow::compare_by`] in your own `TableRow` implementation. With [`TableQuery::apply`] you pass the
+//! comparison function yourself anyway.
+//!
+//! ```
+//! # use leptos_struct_table::*;
+//! # use std::cmp::Ordering;
+//! # use std::collections::VecDeque;
+//! #[derive(Copy, Clone, Debug, PartialEq)]
+//! pub enum ReleaseColumn {
+//!     Name,
+//!     Version,
+//! }
+//!
+//! impl TableColumn for ReleaseColumn {
+//!     fn columns() -> Vec<Self> {
+//!         vec![ReleaseColumn::Name, ReleaseColumn::Version]
+//!     }
+//!
+//!     fn title(&self) -> String {
+//!         format!("{self:?}")
+//!     }
+//! }
+//!
+//! pub struct Release {
+//!     name: String,
+//!     version: String,
+//! }
+//!
+//! impl TableRow for Release {
+//!     type ColumnName = ReleaseColumn;
+//!
+//!     fn cell_text(&self, column: ReleaseColumn) -> String {
+//!         match column {
+//!             ReleaseColumn::Name => self.name.clone(),
+//!             ReleaseColumn::Version => self.version.clone(),
+//!         }
+//!     }
+//!
+//!     fn compare_by(&self, other: &Self, column: ReleaseColumn) -> Ordering {
+//!         match column {
+//!             ReleaseColumn::Version => parse_version(&self.version).cmp(&parse_version(&other.version)),
+//!             _ => self.cell_text(column).cmp(&other.cell_text(column)),
+//!         }
+//!     }
+//! }
+//!
+//! fn parse_version(version: &str) -> Vec<u32> {
+//!     version.split('.').map(|part| part.parse().unwrap_or(0)).collect()
+//! }
+//!
+//! let mut releases = vec![
+//!     Release { name: "Ten".to_string(), version: "0.10.0".to_string() },
+//!     Release { name: "Nine".to_string(), version: "0.9.1".to_string() },
+//! ];
+//! sort_rows(&mut releases, &VecDeque::from([(ReleaseColumn::Version, ColumnSort::Ascending)]));
+//!
+//! assert_eq!(releases[0].name, "Nine");
+//! ```
+//!
+//! ## Collation
+//!
+//! Texts are compared by their bytes by default. This puts "Ärzte" after "Zebra" and "Item 10" before "Item 2".
+//! Return a [`Collation`] from [`TableColumn::collation`] to compare texts case-insensitively, compare numbers inside of
+//! texts by their value (`natural`) or sort letters with diacritics next to their base letter (`locale`).
+//! If you sort with [`TableQuery::apply`] you can use [`Collation::compare`] in your comparison function.
+//!
+//! The locale is only respected when compiled for `wasm32`. There `Intl.Collator` is used so that the rules of the given
+//! locale apply. Everywhere else, for example on the server, the locale is ignored and common Latin letters with diacritics
+//! are sorted as their base letter. This is right for German but not for languages with their own rules like Swedish
+//! which sorts "Å" after "Z".
+//!
+//! ```
+//! # use leptos_struct_table::*;
+//! # use std::cmp::Ordering;
+//! let collation = Collation {
+//!     natural: true,
+//!     case_insensitive: true,
+//!     ..Default::default()
+//! };
+//!
+//! assert_eq!(collation.compare("item 2", "Item 10"), Ordering::Less);
+//! ```
+//!
 //! # Custom Renderers
 //!
 //! Custom renderers can be used to customize almost every aspect of the table.
@@ -262,6 +368,219 @@ pub struct TemperatureMeasurement {
 //!
 //! For more detailed information please have a look at the `custom_renderers_svg` example for a complete customization.
 //!
+//! ## Highlighting Changed Cells
+//!
+//! To highlight a cell for a short time after its value changed, for example when live data is updated, use
+//! [`create_change_flash`] in a custom cell renderer and append the classes from [`TableClassesProvider::cell_changed`].
+//!
+//! ```
+//! # use leptos::*;
+//! # use leptos_struct_table::*;
+//! #[component]
+//! fn FlashingCellRenderer(
+//!     #[prop(into)] class: MaybeSignal<String>,
+//!     #[prop(into)] value: MaybeSignal<f64>,
+//!     index: usize,
+//! ) -> impl IntoView {
+//!     let changed = create_change_flash(value.clone(), DEFAULT_FLASH_DURATION);
+//!     let changed_class = TailwindClassesPreset.cell_changed();
+//!
+//!     let class = move || {
+//!         if changed.get() {
+//!             format!("{} {}", class.get(), changed_class)
+//!         } else {
+//!             class.get()
+//!         }
+//!     };
+//!
+//!     view! {
+//!         <td class=class>{value}</td>
+//!     }
+//! }
+//! ```
+//!
+//! # Keyboard Navigation
+//!
+//! [`DynamicTable`] implements a roving `tabindex`: only one cell can be reached with the Tab key and the arrow keys,
+//! Home, End, PageUp and PageDown move the focus from there. The position is a [`FocusedCell`] that is exposed through
+//! the optional `focused_cell: RwSignal<Option<FocusedCell>>` prop. Enter on a body cell emits the row's click event and
+//! Enter or Space on a header cell toggles the sorting. Clicking a cell focuses it. Keys pressed in an input inside a cell
+//! are left to the input. The component generated by deriving `TableComponent` doesn't support keyboard navigation yet.
+//!
+//! To add it to your own table component, call [`handle_navigation_key`] in the `keydown` handler of the table and pass
+//! a `focused` signal to the default cell and header renderers. This prop is optional, so existing custom renderers
+//! keep working without it. Custom cell renderers can support the focus with [`create_roving_tabindex`].
+//!
+//! Row and header events carry a [`TableEventTrigger`] instead of a `MouseEvent` so they can be triggered with the
+//! mouse as well as with the keyboard. This is a breaking change for custom row and header renderers: they have to
+//! create the events with `trigger: TableEventTrigger::Mouse(mouse_event)` instead of `mouse_event`, see the changelog
+//! for the migration. Click handlers can read the mouse event with `event.trigger.mouse_event()`.
+//!
+//! # Row Interactions
+//!
+//! Besides clicks, [`DefaultTableRowRenderer`] can report double clicks, hovering and long presses to its optional
+//! `on_event` prop and context menu requests to its optional `on_context_menu` prop. Both receive a [`TableRowEvent`]
+//! whose [`TableEventKind`] tells the interactions apart. The browser's context menu is only prevented if
+//! `on_context_menu` is given and a long press is cancelled if the pointer moves more than [`LONG_PRESS_MOVE_TOLERANCE`].
+//! The component generated by deriving `TableComponent` doesn't pass these props, so use the renderer in your own table
+//! components for now. Custom row renderers don't have to accept them.
+//!
+//! The [`TableEventTrigger`] of an event tells whether it came from the mouse, a pointer, touch, the keyboard or was
+//! created from code with [`TableRowEvent::synthetic`]. Custom row renderers have to set the `kind` of the events they
+//! create, usually to [`TableEventKind::Click`].
+//!
+//! ## Context Menu
+//!
+//! A context menu with a list of [`RowAction`]s can be opened from the `on_context_menu` handler of the row renderer.
+//! Every action has a label, an optional icon, a predicate that decides if it is enabled and an async handler that
+//! receives the keys and items of the rows. If the clicked row is selected, the action should apply to the whole
+//! selection, see [`row_action_targets`]. Store a [`RowContextMenuState`] created with [`RowContextMenuState::at_event`]
+//! in a signal and render [`DefaultRowContextMenuRenderer`] while it is set. It focuses its first item, moves the focus
+//! with the arrow keys, Home and End and closes on Escape. The menu is styled through
+//! [`TableClassesProvider::context_menu`] and friends.
+//!
+//! ## Actions Column
+//!
+//! For buttons like edit, view or delete in every row, render the row with a custom row renderer that appends a
+//! [`DefaultTableActionsCellRenderer`] to the cells. Clicks inside of it don't select the row. Add a matching header cell
+//! with [`DefaultTableActionsHeadRenderer`] in a custom `head_row_renderer`. The classes come from
+//! [`TableClassesProvider::actions_cell`] and [`TableClassesProvider::actions_head_cell`].
+//!
+//! ```
+//! # use leptos::*;
+//! # use leptos_struct_table::*;
+//! # use serde::{Deserialize, Serialize};
+//! # use async_trait::async_trait;
+//! # use std::fmt::Display;
+//! #[derive(TableComponent, Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+//! #[table(row_renderer = "BookRowRenderer")]
+//! pub struct Book {
+//!     #[table(key)]
+//!     id: u32,
+//!     title: String,
+//! }
+//!
+//! #[component]
+//! fn BookRowRenderer<F, K>(
+//!     #[prop(into)] class: MaybeSignal<String>,
+//!     #[prop(into)] key: K,
+//!     index: usize,
+//!     #[prop(into)] selected: Signal<bool>,
+//!     on_click: F,
+//!     children: Children,
+//! ) -> impl IntoView
+//! where
+//!     F: Fn(TableRowEvent<K>) + 'static,
+//!     K: Display + Clone + 'static,
+//! {
+//!     let label = format!("Edit book {key}");
+//!
+//!     view! {
+//!         <DefaultTableRowRenderer class=class key=key index=index selected=selected on_click=on_click>
+//!             {children()}
+//!             <DefaultTableActionsCellRenderer class=TailwindClassesPreset.actions_cell()>
+//!                 <button on:click=move |_| logging::log!("{label}")>"Edit"</button>
+//!             </DefaultTableActionsCellRenderer>
+//!         </DefaultTableRowRenderer>
+//!     }
+//! }
+//! ```
+//!
+//! ## Reordering Rows
+//!
+//! If [`DefaultTableRowRenderer`] gets a [`RowReorder`] through its optional `reorder` prop, the row gets a drag handle.
+//! Create one instance with [`RowReorder::new`] in your own table component and pass clones of it to all rows so they
+//! share the state of the current drag. While a row is dragged over another row, the drop position is indicated with the
+//! classes from [`TableClassesProvider::row_drop_indicator`]. When the row is dropped, the `on_reorder` callback receives
+//! a [`TableRowReorderEvent`] with the key, the old and the new index of the row. Apply it to your rows with
+//! [`move_vec_row`] or your own implementation of [`TableDataProvider::move_row`].
+//!
+//! The drag handle is an additional cell in front of the other cells of the row. Render a
+//! [`DefaultTableReorderHeadRenderer`] in front of the header cells of your `head_row_renderer` so that the headers stay
+//! above their columns. Its classes come from [`TableClassesProvider::drag_handle_head`].
+//!
+//! Reordering should be disabled while the table is sorted because the new position couldn't be kept, see
+//! [`is_reorder_enabled`]. The component generated by deriving `TableComponent` doesn't support reordering and custom
+//! row renderers don't have to accept the `reorder` prop.
+//!
+//! # Accessibility
+//!
+//! The default renderers emit the ARIA states that screen readers need: header cells set `aria-sort` and read out the
+//! priority of secondary sort columns, rows set `aria-rowindex`, and `aria-selected` if they are `selectable`.
+//! [`DynamicTable`] can be announced as an interactive grid instead of a static table with its `role` prop, see
+//! [`TableRole`]. It also announces changes of the sorting through a visually hidden `aria-live` region, the
+//! [`TableAnnouncer`], which you can use in your own table components as well.
+//!
+//! Neither [`DynamicTable`] nor the generated component load rows with placeholders. If your own table component renders
+//! [`DefaultTableLoadingRowRenderer`]s, which are hidden from screen readers, set `aria-busy="true"` on the `<tbody>` while
+//! they are displayed and announce [`load_announcement`] through the [`TableAnnouncer`] once the rows arrived.
+//!
+//! # Dynamic Tables
+//!
+//! If the columns are only known at runtime, for example when displaying the results of arbitrary queries, you can't
+//! derive `TableComponent`. Use the [`DynamicTable`] component instead. It takes a list of [`DynamicColumn`]s and rows
+//! of [`CellValue`]s, which can be created from JSON with [`dynamic_rows_from_json`]. It uses the same classes providers,
+//! default renderers and sorting model as the generated components.
+//!
+//! # Refreshing and Loading State
+//!
+//! [`TableController`] bundles what is needed to reload the rows of a table and to show its loading state. The code that
+//! loads the rows, for example a custom table component, calls [`TableController::load_rows`] which keeps the signals
+//! `is_loading()` and `last_error()` up to date so you can show spinners and error messages around the table.
+//! `refresh()`, `refresh_range(range)` and `invalidate_row(key)` queue [`ReloadRequest`]s that the loading code picks up
+//! with [`TableController::take_reload_requests`]. To report errors, implement [`TableDataProvider::try_get_rows`] in your
+//! data provider.
+//!
+//! Please note that the component generated by deriving `TableComponent` doesn't accept a controller.
+//!
+//! Call [`TableController::cancel_pending_loads`] when the sorting changes while rows are still loading.
+//! The results of the outdated loads are then dropped by `load_rows`. To cancel the outdated requests themselves,
+//! implement [`TableDataProvider::try_get_rows_abortable`] and observe the [`AbortSignal`] that is passed to it.
+//!
+//! # Server Side Rendering
+//!
+//! The generated component only loads rows in the browser, so a server rendered table has an empty body until the client
+//! called the data provider. If you render the first rows yourself, for example in a custom table component, load them
+//! already on the server with [`create_initial_rows_resource`]. The rows are serialized into the HTML and reused
+//! when the page is hydrated, so they are not fetched a second time.
+//!
+//! If your data provider is backed by a database or HTTP client that needs a multithreaded runtime on the server,
+//! implement [`SendTableDataProvider`] instead of [`TableDataProvider`].
+//!
+//! # Server Functions
+//!
+//! To page, sort and filter on the server, use [`ServerFnDataProvider`]. It packages the range, the sorting and your
+//! filters into one serializable [`TableQuery`] and passes it to a server function that you provide.
+//! If the server has all rows in memory anyway, [`TableQuery::apply`] does the sorting and paging for you.
+//! Please have a look at the `server_function` example for how this is used.
+//!
+//! # REST APIs
+//!
+//! If the crate feature `rest` is enabled, [`RestDataProvider`] can load rows from REST APIs that return JSON without
+//! having to implement [`TableDataProvider`] yourself. It maps the range to `offset`/`limit` or `page`/`per_page`
+//! parameters, the sorting to a parameter like `sort=title,-year` and reads the rows and the total count from
+//! configurable paths in the response.
+//!
+//! # SQL
+//!
+//! If the crate feature `sql` is enabled and the column name enum implements [`SqlColumn`], a [`TableQuery`] can be
+//! translated into `WHERE`, `ORDER BY` and `LIMIT/OFFSET` clauses with [`TableQuery::to_sql`]. Identifiers are quoted
+//! and filter values, given as [`SqlFilter`]s, are returned as bind parameters. This way sorting, filtering and paging
+//! happen in the database.
+//!
+//! # Export
+//!
+//! Implement [`TableColumn`] for the column name enum and [`TableRow`] for the struct to give access to the column
+//! titles and the displayed cell values. [`export_csv`] uses them to load all rows from a data provider, with its
+//! current sorting and filters, and write them as CSV. [`download_file`] offers the result as a download in the
+//! browser.
+//!
+//! For machine-readable exports use [`JsonExport`]. It writes a JSON array or NDJSON of either the serialized rows
+//! or the displayed values keyed by column title and can be restricted to some rows, for example the selected ones.
+//!
+//! If the crate feature `xlsx` is enabled, [`export_xlsx`] writes an Excel workbook with typed cells. Numbers keep
+//! their precision as number format and dates become Excel dates. Use [`download_bytes`] to download it.
+//!
 //! # Contribution
 //!
 //! All contributions are welcome. Please open an issue or a pull request if you have any ideas or problems.
@@ -269,18 +588,56 @@ pub struct TemperatureMeasurement {
 #![allow(non_snake_case)]
 #![feature(doc_cfg)]
 
+mod abort;
+mod cell_text;
+mod cell_value;
 mod class_providers;
+mod clipboard;
+mod collation;
 mod components;
+mod controller;
 mod data_provider;
+mod export;
+mod focus;
+mod initial_rows;
+#[cfg(feature = "rest")]
+mod rest_data_provider;
+mod row_actions;
+mod send_data_provider;
+mod server_fn_data_provider;
+mod sorting;
+#[cfg(feature = "sql")]
+mod sql;
+mod table_query;
+mod table_row;
 #[cfg(feature = "uuid")]
 pub mod uuid;
 
+pub use abort::*;
+pub use cell_text::*;
+pub use cell_value::*;
 pub use class_providers::*;
+pub use clipboard::*;
+pub use collation::*;
 pub use components::*;
+pub use controller::*;
 pub use data_provider::*;
+pub use export::*;
+pub use focus::*;
+pub use initial_rows::*;
 pub use leptos_struct_table_macro::TableComponent;
+#[cfg(feature = "rest")]
+pub use rest_data_provider::*;
+pub use row_actions::*;
+pub use send_data_provider::*;
 use serde::{Deserialize, Serialize};
+pub use server_fn_data_provider::*;
+pub use sorting::*;
+#[cfg(feature = "sql")]
+pub use sql::*;
 use std::marker::PhantomData;
+pub use table_query::*;
+pub use table_row::*;
 
 /// Type of sorting of a column
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
@@ -298,6 +655,24 @@ impl ColumnSort {
             _ => "",
         }
     }
+
+    /// The value of the `aria-sort` attribute of a header cell.
+    pub fn as_aria_sort(&self) -> &'static str {
+        match self {
+            ColumnSort::Ascending => "ascending",
+            ColumnSort::Descending => "descending",
+            ColumnSort::None => "none",
+        }
+    }
+
+    /// The other direction. [`ColumnSort::None`] stays unsorted.
+    pub fn opposite(&self) -> Self {
+        match self {
+            ColumnSort::Ascending => ColumnSort::Descending,
+            ColumnSort::Descending => ColumnSort::Ascending,
+            ColumnSort::None => ColumnSort::None,
+        }
+    }
 }
 
 /// Type of struct field used to specify that the value of this field is
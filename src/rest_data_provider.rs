@@ -0,0 +1,707 @@
+#![doc(cfg(feature = "rest"))]
+//! Configurable data provider for REST/JSON APIs.
+
+use crate::{ColumnSort, TableDataProvider};
+use async_trait::async_trait;
+use core::fmt::Debug;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::Value;
+use std::cell::Cell;
+use std::collections::VecDeque;
+use std::fmt::{Display, Formatter};
+use std::marker::PhantomData;
+use std::ops::Range;
+use std::rc::Rc;
+
+/// How the requested range of rows is mapped to query parameters.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RestPaging {
+    /// Maps the range to an offset (the index of the first row) and a limit (the number of rows).
+    /// For example `?offset=20&limit=10`.
+    OffsetLimit {
+        /// Name of the offset query parameter.
+        offset: String,
+        /// Name of the limit query parameter.
+        limit: String,
+    },
+    /// Maps the range to a page number and a page size. For example `?page=3&per_page=10`.
+    /// If a range doesn't start at a page boundary, the pages that contain the range are loaded and sliced.
+    PagePerPage {
+        /// Name of the page query parameter.
+        page: String,
+        /// Name of the page size query parameter.
+        per_page: String,
+        /// The number of the first page. Most APIs start counting at `1`.
+        first_page: usize,
+    },
+}
+
+impl Default for RestPaging {
+    fn default() -> Self {
+        Self::OffsetLimit {
+            offset: "offset".to_string(),
+            limit: "limit".to_string(),
+        }
+    }
+}
+
+/// Error that can happen while loading rows with a [`RestDataProvider`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RestDataProviderError {
+    /// The request couldn't be sent or the response couldn't be read.
+    Request(String),
+    /// The server responded with a status code that is not a success.
+    Status(u16),
+    /// The response is not valid JSON or the rows couldn't be deserialized.
+    Json(String),
+    /// The response doesn't contain a value at this path.
+    MissingPath(String),
+}
+
+impl Display for RestDataProviderError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Request(error) => write!(f, "Request failed: {error}"),
+            Self::Status(status) => write!(f, "Server responded with HTTP status {status}"),
+            Self::Json(error) => write!(f, "Invalid JSON response: {error}"),
+            Self::MissingPath(path) => write!(f, "Response has no value at path \"{path}\""),
+        }
+    }
+}
+
+impl std::error::Error for RestDataProviderError {}
+
+/// Data provider that loads rows from a REST API that returns JSON.
+///
+/// The URL is built from a template. The range of rows is mapped to query parameters as configured
+/// by [`RestPaging`] and the sorting is mapped to a parameter like `sort=title,-year` where a `-`
+/// denotes descending order. If the template contains the placeholders `{offset}`, `{limit}`, `{page}`,
+/// `{per_page}` or `{sort}` they are replaced instead of appending query parameters.
+///
+/// This is only available when the **crate feature `rest`** is enabled.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// # use serde::{Deserialize, Serialize};
+/// # use async_trait::async_trait;
+/// #[derive(TableComponent, Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+/// pub struct Book {
+///     #[table(key)]
+///     id: u32,
+///     title: String,
+///     publication_year: u16,
+/// }
+///
+/// # fn test() {
+/// // Loads for example https://example.com/api/books?page=1&per_page=20&sort=-publication_year
+/// // and expects a response like `{ "data": { "items": [...], "total": 123 } }`
+/// let provider = RestDataProvider::<Book, BookColumnName>::new("https://example.com/api/books")
+///     .with_paging(RestPaging::PagePerPage {
+///         page: "page".to_string(),
+///         per_page: "per_page".to_string(),
+///         first_page: 1,
+///     })
+///     .with_rows_path("data.items")
+///     .with_total_path("data.total");
+///
+/// let items = create_rw_signal(provider);
+///
+/// view! {
+///     <BookTable items=items />
+/// };
+/// # }
+/// ```
+pub struct RestDataProvider<T, C> {
+    client: reqwest::Client,
+    url_template: String,
+    headers: Vec<(String, String)>,
+    paging: RestPaging,
+    sort_param: String,
+    column_field: Rc<dyn Fn(C) -> String>,
+    rows_path: String,
+    total_path: Option<String>,
+    total_count: Rc<Cell<Option<usize>>>,
+    sorting: VecDeque<(C, ColumnSort)>,
+    _row: PhantomData<T>,
+}
+
+impl<T, C> RestDataProvider<T, C>
+where
+    C: Debug + 'static,
+{
+    /// Creates a new provider for the given URL template. Uses `offset` and `limit` query parameters
+    /// for paging and expects the response to be a JSON array of rows.
+    /// Columns are mapped to their snake cased name for sorting, e.g. `BookColumnName::PublicationYear`
+    /// becomes `publication_year`.
+    pub fn new(url_template: impl Into<String>) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            url_template: url_template.into(),
+            headers: vec![],
+            paging: RestPaging::default(),
+            sort_param: "sort".to_string(),
+            column_field: Rc::new(|column| to_snake_case(&format!("{column:?}"))),
+            rows_path: "".to_string(),
+            total_path: None,
+            total_count: Rc::new(Cell::new(None)),
+            sorting: VecDeque::new(),
+            _row: PhantomData,
+        }
+    }
+}
+
+impl<T, C> RestDataProvider<T, C> {
+    /// Sets how the range of rows is mapped to query parameters.
+    pub fn with_paging(mut self, paging: RestPaging) -> Self {
+        self.paging = paging;
+        self
+    }
+
+    /// Sets the name of the query parameter for sorting. Defaults to `sort`.
+    pub fn with_sort_param(mut self, sort_param: impl Into<String>) -> Self {
+        self.sort_param = sort_param.into();
+        self
+    }
+
+    /// Sets how a column is mapped to the field name that the API expects for sorting.
+    pub fn with_column_field(mut self, column_field: impl Fn(C) -> String + 'static) -> Self {
+        self.column_field = Rc::new(column_field);
+        self
+    }
+
+    /// Sets the path of the rows array inside the JSON response. Path segments are separated by dots
+    /// and array elements are accessed by their index, e.g. `data.items`. Defaults to the empty path
+    /// which means the response itself is the array of rows.
+    pub fn with_rows_path(mut self, rows_path: impl Into<String>) -> Self {
+        self.rows_path = rows_path.into();
+        self
+    }
+
+    /// Sets the path of the total number of rows inside the JSON response, e.g. `meta.total`.
+    /// If set, the total is available through [`total_count`](RestDataProvider::total_count)
+    /// and ranges beyond it are not requested.
+    pub fn with_total_path(mut self, total_path: impl Into<String>) -> Self {
+        self.total_path = Some(total_path.into());
+        self
+    }
+
+    /// Adds a header that is sent with every request, e.g. for authorization.
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Sets the HTTP client that is used for requests.
+    pub fn with_client(mut self, client: reqwest::Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    /// The total number of rows as reported by the last response. Only available if a
+    /// [total path](RestDataProvider::with_total_path) is configured.
+    pub fn total_count(&self) -> Option<usize> {
+        self.total_count.get()
+    }
+
+    /// Returns the URLs that are requested to load the rows in `range` together with the number
+    /// of rows that have to be skipped at the start of the first response.
+    pub fn urls_for_range(&self, range: Range<usize>) -> (Vec<String>, usize)
+    where
+        C: Copy,
+    {
+        match &self.paging {
+            RestPaging::OffsetLimit { offset, limit } => {
+                let url = self.build_url(&[
+                    ("offset", offset, range.start),
+                    ("limit", limit, range.len()),
+                ]);
+
+                (vec![url], 0)
+            }
+            RestPaging::PagePerPage {
+                page,
+                per_page,
+                first_page,
+            } => {
+                let page_size = range.len().max(1);
+                let first = range.start / page_size;
+                let last = (range.end.max(1) - 1) / page_size;
+
+                let urls = (first..=last.max(first))
+                    .map(|index| {
+                        self.build_url(&[
+                            ("page", page, index + first_page),
+                            ("per_page", per_page, page_size),
+                        ])
+                    })
+                    .collect();
+
+                (urls, range.start - first * page_size)
+            }
+        }
+    }
+
+    fn build_url(&self, paging: &[(&str, &String, usize)]) -> String
+    where
+        C: Copy,
+    {
+        let mut url = self.url_template.clone();
+        let mut query = vec![];
+
+        let mut params = paging
+            .iter()
+            .map(|(placeholder, name, value)| (*placeholder, name.as_str(), value.to_string()))
+            .collect::<Vec<_>>();
+
+        let sort = self
+            .sorting
+            .iter()
+            .filter_map(|(column, sort)| match sort {
+                ColumnSort::Ascending => Some((self.column_field)(*column)),
+                ColumnSort::Descending => Some(format!("-{}", (self.column_field)(*column))),
+                ColumnSort::None => None,
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+
+        if !sort.is_empty() {
+            params.push(("sort", self.sort_param.as_str(), sort));
+        }
+
+        for (placeholder, name, value) in params {
+            let value = encode_query_value(&value);
+            let placeholder = format!("{{{placeholder}}}");
+
+            if url.contains(&placeholder) {
+                url = url.replace(&placeholder, &value);
+            } else {
+                query.push(format!("{name}={value}"));
+            }
+        }
+
+        // placeholders that have no value like an unused `{sort}`
+        for placeholder in ["{offset}", "{limit}", "{page}", "{per_page}", "{sort}"] {
+            url = url.replace(placeholder, "");
+        }
+
+        if !query.is_empty() {
+            url.push(if url.contains('?') { '&' } else { '?' });
+            url.push_str(&query.join("&"));
+        }
+
+        url
+    }
+}
+
+impl<T, C> RestDataProvider<T, C>
+where
+    T: DeserializeOwned,
+    C: Copy,
+{
+    /// Loads the rows in `range`. This is what [`TableDataProvider::try_get_rows`] calls
+    /// but with a typed error.
+    pub async fn fetch_rows(&self, range: Range<usize>) -> Result<Vec<T>, RestDataProviderError> {
+        if range.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let (urls, skip) = self.urls_for_range(range.clone());
+
+        let mut rows = vec![];
+        // only the total of this load counts because rows might have been added or removed since the last one
+        let mut row_count = range.len();
+
+        for url in urls {
+            let response = self.fetch_json(&url).await?;
+
+            if let Some(total_path) = &self.total_path {
+                let total = value_at_path(&response, total_path)
+                    .and_then(Value::as_u64)
+                    .ok_or_else(|| RestDataProviderError::MissingPath(total_path.clone()))?;
+
+                self.total_count.set(Some(total as usize));
+                row_count = row_count.min((total as usize).saturating_sub(range.start));
+            }
+
+            let page = value_at_path(&response, &self.rows_path)
+                .ok_or_else(|| RestDataProviderError::MissingPath(self.rows_path.clone()))?;
+
+            let page = Vec::<T>::deserialize(page)
+                .map_err(|error| RestDataProviderError::Json(error.to_string()))?;
+
+            rows.extend(page);
+        }
+
+        Ok(rows.into_iter().skip(skip).take(row_count).collect())
+    }
+
+    async fn fetch_json(&self, url: &str) -> Result<Value, RestDataProviderError> {
+        let mut request = self.client.get(url);
+
+        for (name, value) in &self.headers {
+            request = request.header(name, value);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|error| RestDataProviderError::Request(error.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(RestDataProviderError::Status(response.status().as_u16()));
+        }
+
+        response
+            .json::<Value>()
+            .await
+            .map_err(|error| RestDataProviderError::Json(error.to_string()))
+    }
+}
+
+impl<T, C> Clone for RestDataProvider<T, C>
+where
+    C: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client.clone(),
+            url_template: self.url_template.clone(),
+            headers: self.headers.clone(),
+            paging: self.paging.clone(),
+            sort_param: self.sort_param.clone(),
+            column_field: Rc::clone(&self.column_field),
+            rows_path: self.rows_path.clone(),
+            total_path: self.total_path.clone(),
+            total_count: Rc::clone(&self.total_count),
+            sorting: self.sorting.clone(),
+            _row: PhantomData,
+        }
+    }
+}
+
+impl<T, C> PartialEq for RestDataProvider<T, C>
+where
+    C: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.url_template == other.url_template
+            && self.headers == other.headers
+            && self.paging == other.paging
+            && self.sort_param == other.sort_param
+            && Rc::ptr_eq(&self.column_field, &other.column_field)
+            && self.rows_path == other.rows_path
+            && self.total_path == other.total_path
+            && self.sorting == other.sorting
+    }
+}
+
+impl<T, C> Debug for RestDataProvider<T, C>
+where
+    C: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RestDataProvider")
+            .field("url_template", &self.url_template)
+            .field("paging", &self.paging)
+            .field("sort_param", &self.sort_param)
+            .field("rows_path", &self.rows_path)
+            .field("total_path", &self.total_path)
+            .field("sorting", &self.sorting)
+            .finish()
+    }
+}
+
+#[async_trait(?Send)]
+impl<T, C> TableDataProvider<T> for RestDataProvider<T, C>
+where
+    T: Debug + PartialEq + DeserializeOwned,
+    C: Copy,
+{
+    type ColumnName = C;
+
+    async fn get_rows(&self, range: Range<usize>) -> Vec<T> {
+        self.fetch_rows(range).await.unwrap_or_default()
+    }
+
+    async fn try_get_rows(&self, range: Range<usize>) -> Result<Vec<T>, String> {
+        self.fetch_rows(range)
+            .await
+            .map_err(|error| error.to_string())
+    }
+
+    fn set_sorting(&mut self, sorting: &VecDeque<(Self::ColumnName, ColumnSort)>) {
+        self.sorting = sorting.clone();
+        self.total_count.set(None);
+    }
+}
+
+fn value_at_path<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.')
+        .filter(|segment| !segment.is_empty())
+        .try_fold(value, |value, segment| match value {
+            Value::Array(array) => array.get(segment.parse::<usize>().ok()?),
+            _ => value.get(segment),
+        })
+}
+
+fn encode_query_value(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b',' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
+}
+
+fn to_snake_case(name: &str) -> String {
+    let mut snake_case = String::with_capacity(name.len() + 4);
+
+    for (index, char) in name.chars().enumerate() {
+        if char.is_uppercase() {
+            if index > 0 {
+                snake_case.push('_');
+            }
+            snake_case.extend(char.to_lowercase());
+        } else {
+            snake_case.push(char);
+        }
+    }
+
+    snake_case
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::thread::JoinHandle;
+
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    enum BookColumn {
+        Title,
+        PublicationYear,
+    }
+
+    fn provider(url_template: &str) -> RestDataProvider<u32, BookColumn> {
+        RestDataProvider::new(url_template)
+    }
+
+    fn page_per_page() -> RestPaging {
+        RestPaging::PagePerPage {
+            page: "page".to_string(),
+            per_page: "per_page".to_string(),
+            first_page: 1,
+        }
+    }
+
+    /// Answers one request per response with the given status line and JSON body and
+    /// returns the request lines that were received.
+    fn serve(responses: Vec<(&'static str, &'static str)>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = std::thread::spawn(move || {
+            let mut requests = vec![];
+
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+
+                let mut buffer = [0; 4096];
+                let read = stream.read(&mut buffer).unwrap();
+                let request = String::from_utf8_lossy(&buffer[..read]);
+                requests.push(request.lines().next().unwrap_or_default().to_string());
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+
+            requests
+        });
+
+        (url, handle)
+    }
+
+    #[test]
+    fn offset_limit_urls() {
+        let (urls, skip) = provider("https://example.com/books").urls_for_range(20..30);
+
+        assert_eq!(urls, vec!["https://example.com/books?offset=20&limit=10"]);
+        assert_eq!(skip, 0);
+    }
+
+    #[test]
+    fn offset_limit_urls_append_to_existing_query_and_add_sorting() {
+        let mut provider = provider("https://example.com/books?lang=en");
+        provider.set_sorting(&VecDeque::from([
+            (BookColumn::PublicationYear, ColumnSort::Descending),
+            (BookColumn::Title, ColumnSort::Ascending),
+        ]));
+
+        let (urls, _) = provider.urls_for_range(0..5);
+
+        assert_eq!(
+            urls,
+            vec!["https://example.com/books?lang=en&offset=0&limit=5&sort=-publication_year,title"]
+        );
+    }
+
+    #[test]
+    fn page_per_page_urls_for_a_whole_page() {
+        let (urls, skip) = provider("https://example.com/books")
+            .with_paging(page_per_page())
+            .urls_for_range(20..30);
+
+        assert_eq!(urls, vec!["https://example.com/books?page=3&per_page=10"]);
+        assert_eq!(skip, 0);
+    }
+
+    #[test]
+    fn page_per_page_urls_for_a_range_that_straddles_pages() {
+        let (urls, skip) = provider("https://example.com/books")
+            .with_paging(page_per_page())
+            .urls_for_range(15..25);
+
+        assert_eq!(
+            urls,
+            vec![
+                "https://example.com/books?page=2&per_page=10",
+                "https://example.com/books?page=3&per_page=10",
+            ]
+        );
+        assert_eq!(skip, 5);
+    }
+
+    #[test]
+    fn placeholders_are_substituted() {
+        let mut provider = provider("https://example.com/books/{page}/{per_page}?order={sort}")
+            .with_paging(page_per_page())
+            .with_column_field(|column| format!("{column:?}").to_lowercase());
+        provider.set_sorting(&VecDeque::from([(
+            BookColumn::Title,
+            ColumnSort::Descending,
+        )]));
+
+        let (urls, _) = provider.urls_for_range(0..10);
+
+        assert_eq!(urls, vec!["https://example.com/books/1/10?order=-title"]);
+    }
+
+    #[test]
+    fn unused_placeholders_are_removed() {
+        let (urls, _) =
+            provider("https://example.com/books?sort={sort}&from={offset}").urls_for_range(5..10);
+
+        assert_eq!(urls, vec!["https://example.com/books?sort=&from=5&limit=5"]);
+    }
+
+    #[test]
+    fn value_at_path_follows_objects_and_arrays() {
+        let value = serde_json::json!({ "data": { "items": [1, 2, { "total": 3 }] } });
+
+        assert_eq!(value_at_path(&value, ""), Some(&value));
+        assert_eq!(
+            value_at_path(&value, "data.items.1"),
+            Some(&serde_json::json!(2))
+        );
+        assert_eq!(
+            value_at_path(&value, "data.items.2.total"),
+            Some(&serde_json::json!(3))
+        );
+        assert_eq!(value_at_path(&value, "data.rows"), None);
+        assert_eq!(value_at_path(&value, "data.items.3"), None);
+        assert_eq!(value_at_path(&value, "data.items.first"), None);
+    }
+
+    #[test]
+    fn encode_query_value_escapes_reserved_characters() {
+        assert_eq!(encode_query_value("-title,year_2.x~"), "-title,year_2.x~");
+        assert_eq!(encode_query_value("a b&c=d"), "a%20b%26c%3Dd");
+        assert_eq!(encode_query_value("ä"), "%C3%A4");
+    }
+
+    #[tokio::test]
+    async fn fetch_rows_slices_pages_and_reads_the_total() {
+        let (url, server) = serve(vec![
+            ("200 OK", r#"{"data":{"items":[1,2,3]},"meta":{"total":7}}"#),
+            ("200 OK", r#"{"data":{"items":[4,5,6]},"meta":{"total":7}}"#),
+        ]);
+
+        let provider = RestDataProvider::<u32, BookColumn>::new(format!("{url}/books"))
+            .with_paging(page_per_page())
+            .with_rows_path("data.items")
+            .with_total_path("meta.total");
+
+        assert_eq!(provider.fetch_rows(1..4).await, Ok(vec![2, 3, 4]));
+        assert_eq!(provider.total_count(), Some(7));
+
+        assert_eq!(
+            server.join().unwrap(),
+            vec![
+                "GET /books?page=1&per_page=3 HTTP/1.1",
+                "GET /books?page=2&per_page=3 HTTP/1.1",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn fetch_rows_requests_rows_past_a_previous_total() {
+        let (url, server) = serve(vec![
+            ("200 OK", r#"{"items":[],"total":0}"#),
+            ("200 OK", r#"{"items":[1,2],"total":2}"#),
+            ("200 OK", r#"{"items":[3,4],"total":4}"#),
+        ]);
+
+        let provider = RestDataProvider::<u32, BookColumn>::new(format!("{url}/books"))
+            .with_rows_path("items")
+            .with_total_path("total");
+
+        assert_eq!(provider.fetch_rows(0..2).await, Ok(vec![]));
+        assert_eq!(provider.total_count(), Some(0));
+
+        // rows were added on the server in the meantime
+        assert_eq!(provider.fetch_rows(0..2).await, Ok(vec![1, 2]));
+        assert_eq!(provider.fetch_rows(2..4).await, Ok(vec![3, 4]));
+        assert_eq!(provider.total_count(), Some(4));
+
+        assert_eq!(
+            server.join().unwrap(),
+            vec![
+                "GET /books?offset=0&limit=2 HTTP/1.1",
+                "GET /books?offset=0&limit=2 HTTP/1.1",
+                "GET /books?offset=2&limit=2 HTTP/1.1",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn fetch_rows_reports_status_errors_and_missing_paths() {
+        let (url, server) = serve(vec![
+            ("500 Internal Server Error", "{}"),
+            ("200 OK", r#"{"items":[1,2,3]}"#),
+        ]);
+
+        let provider = RestDataProvider::<u32, BookColumn>::new(format!("{url}/books"))
+            .with_rows_path("data.items");
+
+        assert_eq!(
+            provider.fetch_rows(0..3).await,
+            Err(RestDataProviderError::Status(500))
+        );
+        assert_eq!(
+            provider.fetch_rows(0..3).await,
+            Err(RestDataProviderError::MissingPath("data.items".to_string()))
+        );
+
+        server.join().unwrap();
+    }
+}
@@ -0,0 +1,202 @@
+use std::fmt::{Debug, Formatter};
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+/// The rows an action is applied to. Every entry is the key of a row together with the row itself.
+pub type RowActionTargets<K, T> = Vec<(K, T)>;
+
+type RowActionEnabledFn<K, T> = Rc<dyn Fn(&[(K, T)]) -> bool>;
+type RowActionHandlerFn<K, T> =
+    Rc<dyn Fn(RowActionTargets<K, T>) -> Pin<Box<dyn Future<Output = ()>>>>;
+
+/// An action that can be applied to rows, for example from the row context menu.
+/// Display a list of actions with [`DefaultRowContextMenuRenderer`](crate::DefaultRowContextMenuRenderer).
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// # #[derive(Clone)] struct Book { id: u32, title: String }
+/// # async fn delete_books(ids: Vec<u32>) {}
+/// let actions: Vec<RowAction<u32, Book>> = vec![
+///     RowAction::new("Copy ID", |rows: RowActionTargets<u32, Book>| async move {
+///         // ...
+///     })
+///     .with_enabled(|rows| rows.len() == 1),
+///     RowAction::new("Delete", |rows: RowActionTargets<u32, Book>| async move {
+///         delete_books(rows.into_iter().map(|(id, _)| id).collect()).await;
+///     })
+///     .with_icon("🗑"),
+/// ];
+/// ```
+pub struct RowAction<K, T> {
+    /// The label that is displayed in the menu.
+    pub label: String,
+    /// An optional icon that is displayed in front of the label. Rendered as text, so this can be an emoji
+    /// or a ligature of an icon font.
+    pub icon: Option<String>,
+    enabled: Option<RowActionEnabledFn<K, T>>,
+    handler: RowActionHandlerFn<K, T>,
+}
+
+impl<K, T> RowAction<K, T> {
+    /// Creates an action with the given label. `handler` is called with all rows the action is applied to.
+    pub fn new<F, Fut>(label: impl Into<String>, handler: F) -> Self
+    where
+        F: Fn(RowActionTargets<K, T>) -> Fut + 'static,
+        Fut: Future<Output = ()> + 'static,
+    {
+        Self {
+            label: label.into(),
+            icon: None,
+            enabled: None,
+            handler: Rc::new(move |rows| Box::pin(handler(rows))),
+        }
+    }
+
+    /// Sets the icon that is displayed in front of the label.
+    pub fn with_icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Sets a predicate that decides whether the action can be applied to the given rows.
+    /// Disabled actions are still displayed but can't be clicked. By default actions are always enabled.
+    pub fn with_enabled(mut self, enabled: impl Fn(&[(K, T)]) -> bool + 'static) -> Self {
+        self.enabled = Some(Rc::new(enabled));
+        self
+    }
+
+    /// Returns `true` if the action can be applied to `rows`. Actions are never enabled for an empty list of rows.
+    pub fn is_enabled(&self, rows: &[(K, T)]) -> bool {
+        match &self.enabled {
+            _ if rows.is_empty() => false,
+            Some(enabled) => enabled(rows),
+            None => true,
+        }
+    }
+
+    /// Applies the action to `rows`.
+    pub async fn run(&self, rows: RowActionTargets<K, T>) {
+        (self.handler)(rows).await
+    }
+}
+
+impl<K, T> Clone for RowAction<K, T> {
+    fn clone(&self) -> Self {
+        Self {
+            label: self.label.clone(),
+            icon: self.icon.clone(),
+            enabled: self.enabled.clone(),
+            handler: Rc::clone(&self.handler),
+        }
+    }
+}
+
+impl<K, T> Debug for RowAction<K, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RowAction")
+            .field("label", &self.label)
+            .field("icon", &self.icon)
+            .finish()
+    }
+}
+
+impl<K, T> PartialEq for RowAction<K, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.label == other.label
+            && self.icon == other.icon
+            && Rc::ptr_eq(&self.handler, &other.handler)
+    }
+}
+
+/// Determines the rows an action is applied to when it was requested on the row with `key`.
+/// If that row is part of the selection, the action applies to the whole selection. Otherwise only to the row itself.
+pub fn row_action_targets<K, T>(key: K, item: T, selection: &[(K, T)]) -> RowActionTargets<K, T>
+where
+    K: PartialEq + Clone,
+    T: Clone,
+{
+    if selection
+        .iter()
+        .any(|(selected_key, _)| *selected_key == key)
+    {
+        selection.to_vec()
+    } else {
+        vec![(key, item)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    fn rows(keys: &[u32]) -> RowActionTargets<u32, String> {
+        keys.iter()
+            .map(|key| (*key, format!("Row {key}")))
+            .collect()
+    }
+
+    fn noop() -> RowAction<u32, String> {
+        RowAction::new("Noop", |_| async {})
+    }
+
+    #[test]
+    fn actions_are_enabled_by_default() {
+        assert!(noop().is_enabled(&rows(&[1])));
+        assert!(noop().is_enabled(&rows(&[1, 2, 3])));
+    }
+
+    #[test]
+    fn actions_are_never_enabled_without_rows() {
+        let action = noop().with_enabled(|_| true);
+
+        assert!(!noop().is_enabled(&[]));
+        assert!(!action.is_enabled(&[]));
+    }
+
+    #[test]
+    fn the_predicate_decides_whether_an_action_is_enabled() {
+        let action = noop().with_enabled(|rows| rows.len() == 1);
+
+        assert!(action.is_enabled(&rows(&[1])));
+        assert!(!action.is_enabled(&rows(&[1, 2])));
+    }
+
+    #[test]
+    fn run_passes_the_rows_to_the_handler() {
+        let received = Rc::new(RefCell::new(Vec::new()));
+        let action = RowAction::new("Collect", {
+            let received = Rc::clone(&received);
+            move |rows: RowActionTargets<u32, String>| {
+                let received = Rc::clone(&received);
+                async move { received.borrow_mut().extend(rows) }
+            }
+        });
+
+        futures::executor::block_on(action.run(rows(&[2, 3])));
+        futures::executor::block_on(action.clone().run(rows(&[4])));
+
+        assert_eq!(*received.borrow(), rows(&[2, 3, 4]));
+    }
+
+    #[test]
+    fn targets_the_selection_if_the_row_is_selected() {
+        let selection = rows(&[1, 3]);
+
+        assert_eq!(
+            row_action_targets(3, "Row 3".to_string(), &selection),
+            selection
+        );
+    }
+
+    #[test]
+    fn targets_only_the_row_if_it_is_not_selected() {
+        assert_eq!(
+            row_action_targets(2, "Row 2".to_string(), &rows(&[1, 3])),
+            rows(&[2])
+        );
+        assert_eq!(row_action_targets(2, "Row 2".to_string(), &[]), rows(&[2]));
+    }
+}
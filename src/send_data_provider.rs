@@ -0,0 +1,231 @@
+use crate::{ColumnSort, TableDataProvider};
+use async_trait::async_trait;
+use core::fmt::Debug;
+use std::collections::VecDeque;
+use std::ops::{Deref, DerefMut, Range};
+
+/// The `Send` variant of [`TableDataProvider`].
+///
+/// [`TableDataProvider`] returns futures that are not `Send` which is fine in the browser but means that it can't
+/// be used on a multithreaded runtime like Tokio, for example when the table is rendered on the server with Axum.
+/// Implement this trait instead if your provider is backed by a `Send` client like `sqlx` or `reqwest`.
+/// Then the same provider can be awaited from server code (e.g. inside `tokio::spawn`) and, wrapped in
+/// [`SendDataProvider`], be passed to the `items` prop of the generated component.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// # use serde::{Deserialize, Serialize};
+/// # use async_trait::async_trait;
+/// # use std::ops::Range;
+/// #[derive(TableComponent, Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+/// pub struct Book {
+///     #[table(key)]
+///     id: u32,
+///     title: String,
+/// }
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// pub struct BookDataProvider;
+///
+/// #[async_trait]
+/// impl SendTableDataProvider<Book> for BookDataProvider {
+///     type ColumnName = BookColumnName;
+///
+///     async fn get_rows(&self, range: Range<usize>) -> Vec<Book> {
+///         // query your database here
+///         vec![]
+///     }
+/// }
+///
+/// # fn test() {
+/// let items = create_rw_signal(SendDataProvider(BookDataProvider));
+///
+/// view! {
+///     <BookTable items=items />
+/// };
+/// # }
+/// ```
+#[async_trait]
+pub trait SendTableDataProvider<T>: Send + Sync
+where
+    T: Debug + PartialEq + Send,
+{
+    /// This is generated by deriving TableComponent. It's an enum with all the column names.
+    type ColumnName: Copy + Send;
+
+    /// Same as [`TableDataProvider::get_rows`] but returns a `Send` future.
+    async fn get_rows(&self, range: Range<usize>) -> Vec<T>;
+
+    /// Same as [`TableDataProvider::try_get_rows`] but returns a `Send` future.
+    /// The default implementation calls [`get_rows`](SendTableDataProvider::get_rows) and never fails.
+    async fn try_get_rows(&self, range: Range<usize>) -> Result<Vec<T>, String> {
+        Ok(self.get_rows(range).await)
+    }
+
+    #[allow(unused_variables)]
+    /// Same as [`TableDataProvider::set_sorting`].
+    /// The default implementation does nothing.
+    fn set_sorting(&mut self, sorting: &VecDeque<(Self::ColumnName, ColumnSort)>) {
+        // by default do nothing
+    }
+
+    #[allow(unused_variables)]
+    /// Same as [`TableDataProvider::move_row`].
+    /// The default implementation does nothing.
+    fn move_row(&mut self, from_index: usize, new_index: usize) {
+        // by default do nothing
+    }
+
+    #[allow(unused_variables)]
+    /// Same as [`TableDataProvider::update_rows`] but returns a `Send` future.
+    /// The default implementation returns an error because the provider is read-only.
+    async fn update_rows(&self, rows: &[(usize, T)]) -> Result<(), String> {
+        Err("This data provider doesn't support updating rows".to_string())
+    }
+}
+
+/// Wraps a [`SendTableDataProvider`] so it can be used as a [`TableDataProvider`] for the generated component.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub struct SendDataProvider<P>(pub P);
+
+impl<P> Deref for SendDataProvider<P> {
+    type Target = P;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<P> DerefMut for SendDataProvider<P> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[async_trait(?Send)]
+impl<T, P> TableDataProvider<T> for SendDataProvider<P>
+where
+    T: Debug + PartialEq + Send,
+    P: SendTableDataProvider<T>,
+{
+    type ColumnName = P::ColumnName;
+
+    async fn get_rows(&self, range: Range<usize>) -> Vec<T> {
+        self.0.get_rows(range).await
+    }
+
+    async fn try_get_rows(&self, range: Range<usize>) -> Result<Vec<T>, String> {
+        self.0.try_get_rows(range).await
+    }
+
+    fn set_sorting(&mut self, sorting: &VecDeque<(Self::ColumnName, ColumnSort)>) {
+        self.0.set_sorting(sorting)
+    }
+
+    fn move_row(&mut self, from_index: usize, new_index: usize) {
+        self.0.move_row(from_index, new_index)
+    }
+
+    async fn update_rows(&self, rows: &[(usize, T)]) -> Result<(), String> {
+        self.0.update_rows(rows).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Clone, Default)]
+    struct Numbers {
+        sorting: Arc<Mutex<VecDeque<(u8, ColumnSort)>>>,
+        moves: Arc<Mutex<Vec<(usize, usize)>>>,
+    }
+
+    #[async_trait]
+    impl SendTableDataProvider<usize> for Numbers {
+        type ColumnName = u8;
+
+        async fn get_rows(&self, range: Range<usize>) -> Vec<usize> {
+            let descending = self
+                .sorting
+                .lock()
+                .unwrap()
+                .front()
+                .is_some_and(|(_, sort)| *sort == ColumnSort::Descending);
+
+            if descending {
+                range.rev().collect()
+            } else {
+                range.collect()
+            }
+        }
+
+        fn set_sorting(&mut self, sorting: &VecDeque<(u8, ColumnSort)>) {
+            *self.sorting.lock().unwrap() = sorting.clone();
+        }
+
+        fn move_row(&mut self, from_index: usize, new_index: usize) {
+            self.moves.lock().unwrap().push((from_index, new_index));
+        }
+    }
+
+    fn assert_send<F: Send>(future: F) -> F {
+        future
+    }
+
+    #[test]
+    fn futures_are_send() {
+        let provider = Numbers::default();
+
+        let rows = block_on(assert_send(SendTableDataProvider::get_rows(
+            &provider,
+            0..2,
+        )));
+        assert_eq!(rows, vec![0, 1]);
+
+        let rows = block_on(assert_send(provider.try_get_rows(0..2)));
+        assert_eq!(rows, Ok(vec![0, 1]));
+
+        let result = block_on(assert_send(provider.update_rows(&[(0, 5)])));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn can_be_awaited_on_another_thread() {
+        let provider = Numbers::default();
+
+        let rows =
+            std::thread::spawn(move || block_on(SendTableDataProvider::get_rows(&provider, 1..3)))
+                .join()
+                .unwrap();
+
+        assert_eq!(rows, vec![1, 2]);
+    }
+
+    #[test]
+    fn wrapper_forwards_to_the_provider() {
+        let numbers = Numbers::default();
+        let mut provider = SendDataProvider(numbers.clone());
+
+        provider.set_sorting(&VecDeque::from([(0, ColumnSort::Descending)]));
+        assert_eq!(
+            *numbers.sorting.lock().unwrap(),
+            VecDeque::from([(0, ColumnSort::Descending)])
+        );
+
+        let rows = block_on(TableDataProvider::get_rows(&provider, 0..3));
+        assert_eq!(rows, vec![2, 1, 0]);
+
+        let rows = block_on(TableDataProvider::try_get_rows(&provider, 0..2));
+        assert_eq!(rows, Ok(vec![1, 0]));
+
+        TableDataProvider::move_row(&mut provider, 2, 0);
+        assert_eq!(*numbers.moves.lock().unwrap(), vec![(2, 0)]);
+
+        let result = block_on(TableDataProvider::update_rows(&provider, &[(0, 5)]));
+        assert!(result.is_err());
+    }
+}
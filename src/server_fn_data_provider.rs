@@ -0,0 +1,167 @@
+use crate::{ColumnSort, TableDataProvider, TableQuery};
+use async_trait::async_trait;
+use core::fmt::Debug;
+use leptos::ServerFnError;
+use std::collections::VecDeque;
+use std::fmt::Formatter;
+use std::future::Future;
+use std::ops::Range;
+use std::pin::Pin;
+use std::rc::Rc;
+
+type ServerFn<T, C, F> =
+    Rc<dyn Fn(TableQuery<C, F>) -> Pin<Box<dyn Future<Output = Result<Vec<T>, ServerFnError>>>>>;
+
+/// Data provider that loads the rows by calling a server function with a [`TableQuery`].
+/// This way the paging, sorting and filtering happens on the server and only the visible rows are transferred.
+///
+/// On the server you can use [`TableQuery::apply`] if you have all the rows in memory anyway.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_struct_table::*;
+/// # use serde::{Deserialize, Serialize};
+/// # use async_trait::async_trait;
+/// #[derive(TableComponent, Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+/// pub struct Book {
+///     #[table(key)]
+///     id: u32,
+///     title: String,
+/// }
+///
+/// // this is usually a #[server] function
+/// pub async fn get_books(query: TableQuery<BookColumnName>) -> Result<Vec<Book>, ServerFnError> {
+///     // query your database here
+///     Ok(vec![])
+/// }
+///
+/// # fn test() {
+/// let items = create_rw_signal(ServerFnDataProvider::new(get_books));
+///
+/// view! {
+///     <BookTable items=items />
+/// };
+/// # }
+/// ```
+pub struct ServerFnDataProvider<T, C, F = ()> {
+    server_fn: ServerFn<T, C, F>,
+    sorting: VecDeque<(C, ColumnSort)>,
+    filter: F,
+}
+
+impl<T, C, F> ServerFnDataProvider<T, C, F>
+where
+    F: Default,
+{
+    /// Creates a new provider that calls `server_fn` to load rows.
+    pub fn new<S, Fut>(server_fn: S) -> Self
+    where
+        S: Fn(TableQuery<C, F>) -> Fut + 'static,
+        Fut: Future<Output = Result<Vec<T>, ServerFnError>> + 'static,
+    {
+        Self {
+            server_fn: Rc::new(move |query| Box::pin(server_fn(query))),
+            sorting: VecDeque::new(),
+            filter: F::default(),
+        }
+    }
+}
+
+impl<T, C, F> ServerFnDataProvider<T, C, F> {
+    /// Sets the sorting the table starts with.
+    pub fn with_sorting(mut self, sorting: VecDeque<(C, ColumnSort)>) -> Self {
+        self.sorting = sorting;
+        self
+    }
+
+    /// Sets the filter that is passed to the server function.
+    pub fn with_filter(mut self, filter: F) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// The current sorting.
+    pub fn sorting(&self) -> &VecDeque<(C, ColumnSort)> {
+        &self.sorting
+    }
+
+    /// The current filter.
+    pub fn filter(&self) -> &F {
+        &self.filter
+    }
+
+    /// Sets the filter that is passed to the server function.
+    /// Update the signal that holds this provider to make the table reload its rows.
+    pub fn set_filter(&mut self, filter: F) {
+        self.filter = filter;
+    }
+}
+
+impl<T, C, F> Clone for ServerFnDataProvider<T, C, F>
+where
+    C: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            server_fn: Rc::clone(&self.server_fn),
+            sorting: self.sorting.clone(),
+            filter: self.filter.clone(),
+        }
+    }
+}
+
+impl<T, C, F> PartialEq for ServerFnDataProvider<T, C, F>
+where
+    C: PartialEq,
+    F: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.server_fn, &other.server_fn)
+            && self.sorting == other.sorting
+            && self.filter == other.filter
+    }
+}
+
+impl<T, C, F> Debug for ServerFnDataProvider<T, C, F>
+where
+    C: Debug,
+    F: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ServerFnDataProvider")
+            .field("sorting", &self.sorting)
+            .field("filter", &self.filter)
+            .finish()
+    }
+}
+
+#[async_trait(?Send)]
+impl<T, C, F> TableDataProvider<T> for ServerFnDataProvider<T, C, F>
+where
+    T: Debug + PartialEq,
+    C: Copy,
+    F: Clone,
+{
+    type ColumnName = C;
+
+    async fn get_rows(&self, range: Range<usize>) -> Vec<T> {
+        self.try_get_rows(range).await.unwrap_or_default()
+    }
+
+    async fn try_get_rows(&self, range: Range<usize>) -> Result<Vec<T>, String> {
+        let query = TableQuery {
+            range,
+            sorting: self.sorting.clone(),
+            filter: self.filter.clone(),
+        };
+
+        (self.server_fn)(query)
+            .await
+            .map_err(|error| error.to_string())
+    }
+
+    fn set_sorting(&mut self, sorting: &VecDeque<(Self::ColumnName, ColumnSort)>) {
+        self.sorting = sorting.clone();
+    }
+}
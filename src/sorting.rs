@@ -0,0 +1,311 @@
+use crate::{ColumnSort, TableColumn, TableRow};
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+
+/// Sorts `rows` by multiple columns. The `sorting` is a list of column names and the sort order sorted by priority
+/// like it is passed to [`TableDataProvider::set_sorting`](crate::TableDataProvider::set_sorting).
+/// `compare` is called to compare two rows by a single column.
+///
+/// The sort is stable. Rows that compare equal for all columns keep their order.
+pub fn sort_by_columns<T, C, F>(rows: &mut [T], sorting: &VecDeque<(C, ColumnSort)>, compare: F)
+where
+    C: Copy,
+    F: Fn(&T, &T, C) -> Ordering,
+{
+    if sorting.is_empty() {
+        return;
+    }
+
+    rows.sort_by(|a, b| {
+        for (column, sort) in sorting {
+            let ordering = match sort {
+                ColumnSort::Ascending => compare(a, b, *column),
+                ColumnSort::Descending => compare(a, b, *column).reverse(),
+                ColumnSort::None => continue,
+            };
+
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+        }
+
+        Ordering::Equal
+    });
+}
+
+/// Where rows whose value is `None` are placed when a column is sorted, regardless of the sort direction.
+/// See [`TableColumn::none_position`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum NonePosition {
+    #[default]
+    First,
+    Last,
+}
+
+impl NonePosition {
+    fn none_ordering(self) -> Ordering {
+        match self {
+            NonePosition::First => Ordering::Less,
+            NonePosition::Last => Ordering::Greater,
+        }
+    }
+}
+
+/// Sorts `rows` by multiple columns using [`compare_rows`].
+///
+/// The sort is stable. Rows that compare equal for all columns keep their order.
+pub fn sort_rows<T: TableRow>(rows: &mut [T], sorting: &VecDeque<(T::ColumnName, ColumnSort)>) {
+    if sorting.is_empty() {
+        return;
+    }
+
+    rows.sort_by(|a, b| compare_rows(a, b, sorting));
+}
+
+/// Compares two rows by multiple columns in the order of `sorting`.
+///
+/// Columns that aren't [`sortable`](TableColumn::sortable) are ignored. Empty values are placed according to
+/// [`TableColumn::none_position`]. All other values are compared with [`TableRow::compare_by`].
+pub fn compare_rows<T: TableRow>(
+    a: &T,
+    b: &T,
+    sorting: &VecDeque<(T::ColumnName, ColumnSort)>,
+) -> Ordering {
+    for (column, sort) in sorting {
+        if *sort == ColumnSort::None || !column.sortable() {
+            continue;
+        }
+
+        let ordering = match (a.is_cell_none(*column), b.is_cell_none(*column)) {
+            (true, true) => Ordering::Equal,
+            (true, false) => column.none_position().none_ordering(),
+            (false, true) => column.none_position().none_ordering().reverse(),
+            (false, false) => match sort {
+                ColumnSort::Descending => a.compare_by(b, *column).reverse(),
+                _ => a.compare_by(b, *column),
+            },
+        };
+
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+
+    Ordering::Equal
+}
+
+/// Returns the sort direction of `column` in `sorting`. [`ColumnSort::None`] if the column is not sorted.
+pub fn column_sort<C: PartialEq>(sorting: &VecDeque<(C, ColumnSort)>, column: &C) -> ColumnSort {
+    sorting
+        .iter()
+        .find(|(sorted_column, _)| sorted_column == column)
+        .map(|(_, sort)| *sort)
+        .unwrap_or(ColumnSort::None)
+}
+
+/// Returns the sort priority of `column` in `sorting`. `None` if the column is not sorted.
+/// `0` means the column is the primary sort column.
+pub fn column_sort_priority<C: PartialEq>(
+    sorting: &VecDeque<(C, ColumnSort)>,
+    column: &C,
+) -> Option<usize> {
+    sorting
+        .iter()
+        .filter(|(_, sort)| *sort != ColumnSort::None)
+        .position(|(sorted_column, _)| sorted_column == column)
+}
+
+/// Whether the table can be sorted by one or by multiple columns. See [`SortOptions`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum SortMode {
+    /// Clicking a header sorts by this column only.
+    Single,
+    /// Clicking a header sorts by this column only. Shift+clicking a header adds the column to the sorting.
+    #[default]
+    Multi,
+}
+
+/// How clicking the headers changes the sorting. Used by [`toggle_column_sort_with`] and the `sort_options` prop
+/// of [`DynamicTable`](crate::DynamicTable).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub struct SortOptions {
+    /// Whether the table can be sorted by multiple columns.
+    pub mode: SortMode,
+    /// The maximum number of columns the table is sorted by. The columns with the lowest priority are removed
+    /// when more columns are added. `None` means unlimited.
+    pub max_columns: Option<usize>,
+}
+
+/// Updates `sorting` when the header of `column` is clicked.
+/// The sort direction of the column cycles through ascending, descending and unsorted
+/// and the column becomes the one with the highest priority.
+pub fn toggle_column_sort<C: PartialEq>(sorting: &mut VecDeque<(C, ColumnSort)>, column: C) {
+    let options = SortOptions::default();
+
+    toggle_column_sort_with(sorting, column, ColumnSort::Ascending, true, &options);
+}
+
+/// Updates `sorting` when the header of `column` is clicked like [`toggle_column_sort`] but configurable.
+///
+/// The sort direction of the column cycles through `initial_sort`, the opposite direction and unsorted.
+/// If `add_to_sorting` is `true`, usually because Shift was held while clicking, and the sort mode is
+/// [`SortMode::Multi`], the other sorted columns are kept with a lower priority. Otherwise they are removed.
+pub fn toggle_column_sort_with<C: PartialEq>(
+    sorting: &mut VecDeque<(C, ColumnSort)>,
+    column: C,
+    initial_sort: ColumnSort,
+    add_to_sorting: bool,
+    options: &SortOptions,
+) {
+    let initial_sort = match initial_sort {
+        ColumnSort::None => ColumnSort::Ascending,
+        sort => sort,
+    };
+
+    let sort = match column_sort(sorting, &column) {
+        ColumnSort::None => initial_sort,
+        sort if sort == initial_sort => initial_sort.opposite(),
+        _ => ColumnSort::None,
+    };
+
+    if add_to_sorting && options.mode == SortMode::Multi {
+        sorting.retain(|(sorted_column, _)| *sorted_column != column);
+    } else {
+        sorting.clear();
+    }
+
+    if sort != ColumnSort::None {
+        sorting.push_front((column, sort));
+    }
+
+    if let Some(max_columns) = options.max_columns {
+        sorting.truncate(max_columns.max(1));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MULTI: SortOptions = SortOptions {
+        mode: SortMode::Multi,
+        max_columns: None,
+    };
+
+    fn click(
+        sorting: &mut VecDeque<(u8, ColumnSort)>,
+        column: u8,
+        shift: bool,
+        options: &SortOptions,
+    ) {
+        toggle_column_sort_with(sorting, column, ColumnSort::Ascending, shift, options);
+    }
+
+    #[test]
+    fn cycles_through_the_initial_direction_the_opposite_and_unsorted() {
+        let mut sorting = VecDeque::new();
+
+        toggle_column_sort_with(&mut sorting, 0, ColumnSort::Descending, false, &MULTI);
+        assert_eq!(sorting, VecDeque::from([(0, ColumnSort::Descending)]));
+
+        toggle_column_sort_with(&mut sorting, 0, ColumnSort::Descending, false, &MULTI);
+        assert_eq!(sorting, VecDeque::from([(0, ColumnSort::Ascending)]));
+
+        toggle_column_sort_with(&mut sorting, 0, ColumnSort::Descending, false, &MULTI);
+        assert_eq!(sorting, VecDeque::new());
+    }
+
+    #[test]
+    fn initial_sort_none_means_ascending() {
+        let mut sorting = VecDeque::new();
+
+        toggle_column_sort_with(&mut sorting, 0, ColumnSort::None, false, &MULTI);
+        assert_eq!(sorting, VecDeque::from([(0, ColumnSort::Ascending)]));
+    }
+
+    #[test]
+    fn click_without_shift_replaces_the_sorting() {
+        let mut sorting = VecDeque::from([(0, ColumnSort::Ascending), (1, ColumnSort::Descending)]);
+
+        click(&mut sorting, 2, false, &MULTI);
+        assert_eq!(sorting, VecDeque::from([(2, ColumnSort::Ascending)]));
+    }
+
+    #[test]
+    fn click_with_shift_adds_the_column_with_the_highest_priority() {
+        let mut sorting = VecDeque::from([(0, ColumnSort::Ascending), (1, ColumnSort::Descending)]);
+
+        click(&mut sorting, 1, true, &MULTI);
+        assert_eq!(
+            sorting,
+            VecDeque::from([(0, ColumnSort::Ascending)]),
+            "the third click removes the column"
+        );
+
+        click(&mut sorting, 2, true, &MULTI);
+        assert_eq!(
+            sorting,
+            VecDeque::from([(2, ColumnSort::Ascending), (0, ColumnSort::Ascending)])
+        );
+
+        click(&mut sorting, 0, true, &MULTI);
+        assert_eq!(
+            sorting,
+            VecDeque::from([(0, ColumnSort::Descending), (2, ColumnSort::Ascending)])
+        );
+    }
+
+    #[test]
+    fn single_mode_ignores_shift() {
+        let options = SortOptions {
+            mode: SortMode::Single,
+            max_columns: None,
+        };
+        let mut sorting = VecDeque::from([(0, ColumnSort::Ascending)]);
+
+        click(&mut sorting, 1, true, &options);
+        assert_eq!(sorting, VecDeque::from([(1, ColumnSort::Ascending)]));
+    }
+
+    #[test]
+    fn max_columns_drops_the_lowest_priority() {
+        let options = SortOptions {
+            mode: SortMode::Multi,
+            max_columns: Some(2),
+        };
+        let mut sorting = VecDeque::new();
+
+        click(&mut sorting, 0, true, &options);
+        click(&mut sorting, 1, true, &options);
+        click(&mut sorting, 2, true, &options);
+        assert_eq!(
+            sorting,
+            VecDeque::from([(2, ColumnSort::Ascending), (1, ColumnSort::Ascending)])
+        );
+    }
+
+    #[test]
+    fn max_columns_of_zero_still_sorts_by_one_column() {
+        let options = SortOptions {
+            mode: SortMode::Multi,
+            max_columns: Some(0),
+        };
+        let mut sorting = VecDeque::from([(0, ColumnSort::Ascending)]);
+
+        click(&mut sorting, 1, true, &options);
+        assert_eq!(sorting, VecDeque::from([(1, ColumnSort::Ascending)]));
+    }
+
+    #[test]
+    fn toggle_column_sort_keeps_the_other_columns() {
+        let mut sorting = VecDeque::from([(0, ColumnSort::Ascending)]);
+
+        toggle_column_sort(&mut sorting, 1);
+        assert_eq!(
+            sorting,
+            VecDeque::from([(1, ColumnSort::Ascending), (0, ColumnSort::Ascending)])
+        );
+        assert_eq!(column_sort_priority(&sorting, &0), Some(1));
+        assert_eq!(column_sort(&sorting, &2), ColumnSort::None);
+    }
+}
@@ -0,0 +1,618 @@
+#![doc(cfg(feature = "sql"))]
+//! Translate a [`TableQuery`] into SQL clauses on the server.
+
+use crate::{ColumnSort, TableQuery};
+use serde::{Deserialize, Serialize};
+
+/// Maps a column to the SQL identifier that is used in the generated clauses.
+///
+/// Implement this for the column name enum that is generated by deriving `TableComponent` to be able to
+/// call [`TableQuery::to_sql`]. Usually the identifier is the field name.
+pub trait SqlColumn: Copy {
+    /// The SQL identifier of the column. Can be qualified with a table name like `books.pub_year`.
+    fn sql_identifier(&self) -> &'static str;
+}
+
+/// The SQL dialect determines how identifiers are quoted and how bind parameters are written.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum SqlDialect {
+    /// Identifiers are quoted with `"` and parameters are written as `?`.
+    #[default]
+    Sqlite,
+    /// Identifiers are quoted with `"` and parameters are written as `$1`, `$2`, ...
+    Postgres,
+    /// Identifiers are quoted with `` ` `` and parameters are written as `?`.
+    MySql,
+}
+
+impl SqlDialect {
+    /// Quotes an identifier. Every part of a qualified identifier like `books.pub_year` is quoted separately.
+    pub fn quote_identifier(&self, identifier: &str) -> String {
+        let quote = match self {
+            SqlDialect::Sqlite | SqlDialect::Postgres => '"',
+            SqlDialect::MySql => '`',
+        };
+
+        identifier
+            .split('.')
+            .map(|part| {
+                let escaped = part.replace(quote, &format!("{quote}{quote}"));
+                format!("{quote}{escaped}{quote}")
+            })
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+
+    fn placeholder(&self, index: usize) -> String {
+        match self {
+            SqlDialect::Sqlite | SqlDialect::MySql => "?".to_string(),
+            SqlDialect::Postgres => format!("${index}"),
+        }
+    }
+}
+
+/// A value that is bound as a parameter to the generated SQL.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum SqlValue {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Text(String),
+}
+
+macro_rules! sql_value_from {
+    ($variant:ident, $($ty:ty),*) => {
+        $(
+            impl From<$ty> for SqlValue {
+                fn from(value: $ty) -> Self {
+                    SqlValue::$variant(value.into())
+                }
+            }
+        )*
+    };
+}
+
+sql_value_from!(Bool, bool);
+sql_value_from!(Int, i8, i16, i32, i64, u8, u16, u32);
+sql_value_from!(Float, f32, f64);
+sql_value_from!(Text, String, &str);
+
+impl<T: Into<SqlValue>> From<Option<T>> for SqlValue {
+    fn from(value: Option<T>) -> Self {
+        value.map(Into::into).unwrap_or(SqlValue::Null)
+    }
+}
+
+/// A filter condition on a column that can be used as the filter of a [`TableQuery`].
+/// All values are bound as parameters, they never become part of the SQL string.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum SqlFilter<C> {
+    Eq(C, SqlValue),
+    NotEq(C, SqlValue),
+    Lt(C, SqlValue),
+    LtEq(C, SqlValue),
+    Gt(C, SqlValue),
+    GtEq(C, SqlValue),
+    /// `LIKE` with the given pattern, e.g. `%tolkien%`.
+    Like(C, String),
+    In(C, Vec<SqlValue>),
+    IsNull(C),
+    IsNotNull(C),
+    /// All of the filters have to match. Matches everything if empty.
+    And(Vec<SqlFilter<C>>),
+    /// Any of the filters has to match. Matches nothing if empty.
+    Or(Vec<SqlFilter<C>>),
+    /// The filter must not match.
+    Not(Box<SqlFilter<C>>),
+}
+
+/// Types that can be used as the filter of a [`TableQuery`] to generate a `WHERE` clause.
+pub trait ToSqlCondition<C: SqlColumn> {
+    /// Writes the condition without the `WHERE` keyword to `sql` and pushes the bound values to `params`.
+    /// Returns `false` if there is no condition.
+    fn write_sql_condition(
+        &self,
+        dialect: SqlDialect,
+        sql: &mut String,
+        params: &mut Vec<SqlValue>,
+    ) -> bool;
+}
+
+impl<C: SqlColumn> ToSqlCondition<C> for () {
+    fn write_sql_condition(&self, _: SqlDialect, _: &mut String, _: &mut Vec<SqlValue>) -> bool {
+        false
+    }
+}
+
+impl<C: SqlColumn, F: ToSqlCondition<C>> ToSqlCondition<C> for Option<F> {
+    fn write_sql_condition(
+        &self,
+        dialect: SqlDialect,
+        sql: &mut String,
+        params: &mut Vec<SqlValue>,
+    ) -> bool {
+        match self {
+            Some(filter) => filter.write_sql_condition(dialect, sql, params),
+            None => false,
+        }
+    }
+}
+
+/// All filters have to match.
+impl<C: SqlColumn> ToSqlCondition<C> for Vec<SqlFilter<C>> {
+    fn write_sql_condition(
+        &self,
+        dialect: SqlDialect,
+        sql: &mut String,
+        params: &mut Vec<SqlValue>,
+    ) -> bool {
+        if self.is_empty() {
+            return false;
+        }
+
+        write_joined(self, " AND ", dialect, sql, params);
+        true
+    }
+}
+
+impl<C: SqlColumn> ToSqlCondition<C> for SqlFilter<C> {
+    fn write_sql_condition(
+        &self,
+        dialect: SqlDialect,
+        sql: &mut String,
+        params: &mut Vec<SqlValue>,
+    ) -> bool {
+        let mut compare = |column: &C, operator: &str, value: &SqlValue| {
+            params.push(value.clone());
+            sql.push_str(&format!(
+                "{} {operator} {}",
+                dialect.quote_identifier(column.sql_identifier()),
+                dialect.placeholder(params.len())
+            ));
+        };
+
+        match self {
+            SqlFilter::Eq(column, value) => compare(column, "=", value),
+            SqlFilter::NotEq(column, value) => compare(column, "<>", value),
+            SqlFilter::Lt(column, value) => compare(column, "<", value),
+            SqlFilter::LtEq(column, value) => compare(column, "<=", value),
+            SqlFilter::Gt(column, value) => compare(column, ">", value),
+            SqlFilter::GtEq(column, value) => compare(column, ">=", value),
+            SqlFilter::Like(column, pattern) => {
+                compare(column, "LIKE", &SqlValue::Text(pattern.clone()))
+            }
+            SqlFilter::In(column, values) => {
+                if values.is_empty() {
+                    sql.push_str("1 = 0");
+                } else {
+                    let placeholders = values
+                        .iter()
+                        .map(|value| {
+                            params.push(value.clone());
+                            dialect.placeholder(params.len())
+                        })
+                        .collect::<Vec<_>>()
+                        .join(", ");
+
+                    sql.push_str(&format!(
+                        "{} IN ({placeholders})",
+                        dialect.quote_identifier(column.sql_identifier())
+                    ));
+                }
+            }
+            SqlFilter::IsNull(column) => sql.push_str(&format!(
+                "{} IS NULL",
+                dialect.quote_identifier(column.sql_identifier())
+            )),
+            SqlFilter::IsNotNull(column) => sql.push_str(&format!(
+                "{} IS NOT NULL",
+                dialect.quote_identifier(column.sql_identifier())
+            )),
+            SqlFilter::And(filters) => {
+                if filters.is_empty() {
+                    sql.push_str("1 = 1");
+                } else {
+                    write_joined(filters, " AND ", dialect, sql, params);
+                }
+            }
+            SqlFilter::Or(filters) => {
+                if filters.is_empty() {
+                    sql.push_str("1 = 0");
+                } else {
+                    write_joined(filters, " OR ", dialect, sql, params);
+                }
+            }
+            SqlFilter::Not(filter) => {
+                sql.push_str("NOT (");
+                filter.write_sql_condition(dialect, sql, params);
+                sql.push(')');
+            }
+        }
+
+        true
+    }
+}
+
+fn write_joined<C: SqlColumn>(
+    filters: &[SqlFilter<C>],
+    separator: &str,
+    dialect: SqlDialect,
+    sql: &mut String,
+    params: &mut Vec<SqlValue>,
+) {
+    for (index, filter) in filters.iter().enumerate() {
+        if index > 0 {
+            sql.push_str(separator);
+        }
+        sql.push('(');
+        filter.write_sql_condition(dialect, sql, params);
+        sql.push(')');
+    }
+}
+
+/// The SQL clauses generated from a [`TableQuery`] by [`TableQuery::to_sql`].
+/// Each clause is empty if there is nothing to filter or sort by.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct SqlClauses {
+    /// `WHERE ...` clause.
+    pub where_clause: String,
+    /// `ORDER BY ...` clause.
+    pub order_by: String,
+    /// `LIMIT ... OFFSET ...` clause.
+    pub limit_offset: String,
+    /// The values that have to be bound to the parameters in `where_clause` in this order.
+    pub params: Vec<SqlValue>,
+}
+
+impl SqlClauses {
+    /// Returns all clauses in the right order to be appended to a `SELECT ... FROM ...` statement.
+    pub fn to_sql(&self) -> String {
+        [&self.where_clause, &self.order_by, &self.limit_offset]
+            .into_iter()
+            .filter(|clause| !clause.is_empty())
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+impl<C, F> TableQuery<C, F>
+where
+    C: SqlColumn,
+    F: ToSqlCondition<C>,
+{
+    /// Generates the `WHERE`, `ORDER BY` and `LIMIT/OFFSET` clauses for this query.
+    /// Identifiers are quoted and filter values are returned as bind parameters so the result is safe
+    /// to append to your statement.
+    ///
+    /// This is only available when the **crate feature `sql`** is enabled.
+    ///
+    /// ```
+    /// # use leptos_struct_table::*;
+    /// # use std::collections::VecDeque;
+    /// #[derive(Copy, Clone)]
+    /// enum BookColumnName {
+    ///     Title,
+    ///     PublicationYear,
+    /// }
+    ///
+    /// impl SqlColumn for BookColumnName {
+    ///     fn sql_identifier(&self) -> &'static str {
+    ///         match self {
+    ///             BookColumnName::Title => "title",
+    ///             BookColumnName::PublicationYear => "books.pub_year",
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let query = TableQuery {
+    ///     range: 20..30,
+    ///     sorting: VecDeque::from([(BookColumnName::PublicationYear, ColumnSort::Descending)]),
+    ///     filter: vec![SqlFilter::Gt(BookColumnName::PublicationYear, 1900.into())],
+    /// };
+    ///
+    /// let clauses = query.to_sql(SqlDialect::Sqlite);
+    ///
+    /// assert_eq!(
+    ///     clauses.to_sql(),
+    ///     r#"WHERE ("books"."pub_year" > ?) ORDER BY "books"."pub_year" DESC LIMIT 10 OFFSET 20"#
+    /// );
+    /// assert_eq!(clauses.params, vec![SqlValue::Int(1900)]);
+    /// ```
+    pub fn to_sql(&self, dialect: SqlDialect) -> SqlClauses {
+        let mut params = vec![];
+
+        let mut condition = String::new();
+        let where_clause = if self
+            .filter
+            .write_sql_condition(dialect, &mut condition, &mut params)
+        {
+            format!("WHERE {condition}")
+        } else {
+            String::new()
+        };
+
+        let order_by = self
+            .sorting
+            .iter()
+            .filter_map(|(column, sort)| {
+                let direction = match sort {
+                    ColumnSort::Ascending => "ASC",
+                    ColumnSort::Descending => "DESC",
+                    ColumnSort::None => return None,
+                };
+
+                Some(format!(
+                    "{} {direction}",
+                    dialect.quote_identifier(column.sql_identifier())
+                ))
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let order_by = if order_by.is_empty() {
+            order_by
+        } else {
+            format!("ORDER BY {order_by}")
+        };
+
+        let limit_offset = format!(
+            "LIMIT {} OFFSET {}",
+            self.range.end.saturating_sub(self.range.start),
+            self.range.start
+        );
+
+        SqlClauses {
+            where_clause,
+            order_by,
+            limit_offset,
+            params,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusqlite::types::Value;
+    use rusqlite::Connection;
+    use std::collections::VecDeque;
+
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    enum BookColumn {
+        Title,
+        PublicationYear,
+        Quoted,
+    }
+
+    impl SqlColumn for BookColumn {
+        fn sql_identifier(&self) -> &'static str {
+            match self {
+                BookColumn::Title => "title",
+                BookColumn::PublicationYear => "books.pub_year",
+                BookColumn::Quoted => "a\"b",
+            }
+        }
+    }
+
+    fn database() -> Connection {
+        let connection = Connection::open_in_memory().unwrap();
+
+        connection
+            .execute_batch(
+                r#"
+                CREATE TABLE books (title TEXT, pub_year INTEGER, "a""b" TEXT);
+                INSERT INTO books VALUES
+                    ('The Hobbit', 1937, 'x'),
+                    ('Dune', 1965, 'y'),
+                    ('Emma', 1815, NULL),
+                    ('Neuromancer', 1984, 'x'),
+                    ('It''s "quoted"', 2001, 'z');
+                "#,
+            )
+            .unwrap();
+
+        connection
+    }
+
+    fn query<F>(
+        range: std::ops::Range<usize>,
+        sorting: Vec<(BookColumn, ColumnSort)>,
+        filter: F,
+    ) -> TableQuery<BookColumn, F> {
+        TableQuery {
+            range,
+            sorting: VecDeque::from(sorting),
+            filter,
+        }
+    }
+
+    /// Runs the clauses of `query` against the test database and returns the selected titles.
+    fn titles<F: ToSqlCondition<BookColumn>>(query: &TableQuery<BookColumn, F>) -> Vec<String> {
+        let clauses = query.to_sql(SqlDialect::Sqlite);
+        let sql = format!("SELECT title FROM books {}", clauses.to_sql());
+
+        let params = clauses.params.into_iter().map(|value| match value {
+            SqlValue::Null => Value::Null,
+            SqlValue::Bool(value) => Value::Integer(value.into()),
+            SqlValue::Int(value) => Value::Integer(value),
+            SqlValue::Float(value) => Value::Real(value),
+            SqlValue::Text(value) => Value::Text(value),
+        });
+
+        let connection = database();
+        let mut statement = connection.prepare(&sql).unwrap();
+
+        let titles = statement
+            .query_map(rusqlite::params_from_iter(params), |row| row.get(0))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        titles
+    }
+
+    #[test]
+    fn where_order_by_and_limit_run_with_bound_params() {
+        let query = query(
+            1..3,
+            vec![
+                (BookColumn::PublicationYear, ColumnSort::Descending),
+                (BookColumn::Title, ColumnSort::Ascending),
+            ],
+            vec![SqlFilter::Gt(BookColumn::PublicationYear, 1900.into())],
+        );
+
+        assert_eq!(
+            query.to_sql(SqlDialect::Sqlite).to_sql(),
+            r#"WHERE ("books"."pub_year" > ?) ORDER BY "books"."pub_year" DESC, "title" ASC LIMIT 2 OFFSET 1"#
+        );
+        assert_eq!(titles(&query), vec!["Neuromancer", "Dune"]);
+    }
+
+    #[test]
+    fn without_filter_and_sorting_only_pages() {
+        let query = query(3..10, vec![], ());
+
+        assert_eq!(
+            query.to_sql(SqlDialect::Sqlite).to_sql(),
+            "LIMIT 7 OFFSET 3"
+        );
+        assert_eq!(titles(&query).len(), 2);
+    }
+
+    #[test]
+    fn values_are_bound_and_never_part_of_the_sql() {
+        let title = r#"It's "quoted""#;
+        let query = query(
+            0..10,
+            vec![],
+            SqlFilter::Eq(BookColumn::Title, title.into()),
+        );
+
+        let clauses = query.to_sql(SqlDialect::Sqlite);
+
+        assert!(!clauses.where_clause.contains("quoted"));
+        assert_eq!(clauses.params, vec![SqlValue::Text(title.to_string())]);
+        assert_eq!(titles(&query), vec![title]);
+    }
+
+    #[test]
+    fn identifiers_with_quotes_are_escaped() {
+        assert_eq!(SqlDialect::Sqlite.quote_identifier("a\"b"), r#""a""b""#);
+        assert_eq!(
+            SqlDialect::Postgres.quote_identifier("books.a\"b"),
+            r#""books"."a""b""#
+        );
+        assert_eq!(SqlDialect::MySql.quote_identifier("a`b.c"), "`a``b`.`c`");
+
+        let query = query(
+            0..10,
+            vec![(BookColumn::Title, ColumnSort::Ascending)],
+            SqlFilter::Eq(BookColumn::Quoted, "x".into()),
+        );
+
+        assert_eq!(titles(&query), vec!["Neuromancer", "The Hobbit"]);
+    }
+
+    #[test]
+    fn empty_in_and_or_match_nothing() {
+        let empty_in = query(0..10, vec![], SqlFilter::In(BookColumn::Title, vec![]));
+        let empty_or = query(0..10, vec![], SqlFilter::<BookColumn>::Or(vec![]));
+
+        assert_eq!(
+            empty_in.to_sql(SqlDialect::Sqlite).where_clause,
+            "WHERE 1 = 0"
+        );
+        assert!(titles(&empty_in).is_empty());
+        assert!(titles(&empty_or).is_empty());
+    }
+
+    #[test]
+    fn empty_and_matches_everything() {
+        let empty_and = query(0..10, vec![], SqlFilter::<BookColumn>::And(vec![]));
+        let empty_list = query(0..10, vec![], Vec::<SqlFilter<BookColumn>>::new());
+
+        assert_eq!(titles(&empty_and).len(), 5);
+        assert_eq!(empty_list.to_sql(SqlDialect::Sqlite).where_clause, "");
+        assert_eq!(titles(&empty_list).len(), 5);
+    }
+
+    #[test]
+    fn not_negates_the_inner_filter() {
+        let not_matching = query(
+            0..10,
+            vec![(BookColumn::PublicationYear, ColumnSort::Ascending)],
+            SqlFilter::Not(Box::new(SqlFilter::Or(vec![
+                SqlFilter::Like(BookColumn::Title, "The %".to_string()),
+                SqlFilter::IsNull(BookColumn::Quoted),
+            ]))),
+        );
+        let not_empty_in = query(
+            0..10,
+            vec![],
+            SqlFilter::Not(Box::new(SqlFilter::In(BookColumn::Title, vec![]))),
+        );
+
+        assert_eq!(
+            titles(&not_matching),
+            vec!["Dune", "Neuromancer", r#"It's "quoted""#]
+        );
+        assert_eq!(titles(&not_empty_in).len(), 5);
+    }
+
+    #[test]
+    fn in_binds_every_value() {
+        let query = query(
+            0..10,
+            vec![(BookColumn::Title, ColumnSort::Descending)],
+            SqlFilter::In(
+                BookColumn::PublicationYear,
+                vec![1815.into(), 1965.into(), 2100.into()],
+            ),
+        );
+
+        assert_eq!(
+            query.to_sql(SqlDialect::Sqlite).where_clause,
+            r#"WHERE "books"."pub_year" IN (?, ?, ?)"#
+        );
+        assert_eq!(titles(&query), vec!["Emma", "Dune"]);
+    }
+
+    #[test]
+    fn postgres_numbers_params_across_nested_filters() {
+        let query = query(
+            0..10,
+            vec![],
+            vec![
+                SqlFilter::Or(vec![
+                    SqlFilter::Eq(BookColumn::Title, "Dune".into()),
+                    SqlFilter::In(BookColumn::Title, vec!["Emma".into(), "It".into()]),
+                ]),
+                SqlFilter::Not(Box::new(SqlFilter::And(vec![
+                    SqlFilter::GtEq(BookColumn::PublicationYear, 1900.into()),
+                    SqlFilter::IsNotNull(BookColumn::Quoted),
+                    SqlFilter::Like(BookColumn::Quoted, "%x%".to_string()),
+                ]))),
+            ],
+        );
+
+        let clauses = query.to_sql(SqlDialect::Postgres);
+
+        assert_eq!(
+            clauses.where_clause,
+            r#"WHERE (("title" = $1) OR ("title" IN ($2, $3))) AND (NOT (("books"."pub_year" >= $4) AND ("a""b" IS NOT NULL) AND ("a""b" LIKE $5)))"#
+        );
+        assert_eq!(
+            clauses.params,
+            vec![
+                SqlValue::Text("Dune".to_string()),
+                SqlValue::Text("Emma".to_string()),
+                SqlValue::Text("It".to_string()),
+                SqlValue::Int(1900),
+                SqlValue::Text("%x%".to_string()),
+            ]
+        );
+    }
+}
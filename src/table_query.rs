@@ -0,0 +1,183 @@
+use crate::{get_rows_in_range, sort_by_columns, sort_rows, ColumnSort, TableRow};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+use std::ops::Range;
+
+/// Everything that is needed to load rows on the server: the range, the sorting and any filters.
+/// Because it is serializable it can be passed as the single argument to a server function.
+/// See [`ServerFnDataProvider`](crate::ServerFnDataProvider).
+///
+/// `C` is the column name enum that is generated by deriving `TableComponent`.
+/// `F` is your own filter type. If you don't need filters just leave it as `()`.
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct TableQuery<C, F = ()> {
+    /// The range of rows that should be returned.
+    pub range: Range<usize>,
+    /// The sorting of the table. See [`TableDataProvider::set_sorting`](crate::TableDataProvider::set_sorting).
+    pub sorting: VecDeque<(C, ColumnSort)>,
+    /// The filters of the table.
+    pub filter: F,
+}
+
+impl<C: Copy, F> TableQuery<C, F> {
+    /// Sorts `rows` according to this query and returns only the rows in the range.
+    /// `compare` is called to compare two rows by a single column. See [`sort_by_columns`].
+    ///
+    /// This is meant to be used on the server when all the rows are in memory anyway.
+    ///
+    /// ```
+    /// # use leptos::*;
+    /// # use leptos_struct_table::*;
+    /// # use serde::{Deserialize, Serialize};
+    /// # use async_trait::async_trait;
+    /// # use std::cmp::Ordering;
+    /// #[derive(TableComponent, Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    /// pub struct Book {
+    ///     #[table(key)]
+    ///     id: u32,
+    ///     title: String,
+    /// }
+    ///
+    /// // this is usually a #[server] function
+    /// pub async fn get_books(query: TableQuery<BookColumnName>) -> Result<Vec<Book>, ServerFnError> {
+    ///     let books = vec![]; // load from somewhere
+    ///
+    ///     Ok(query.apply(books, |a, b, column| {
+    ///         a.get(column).partial_cmp(&b.get(column)).unwrap_or(Ordering::Equal)
+    ///     }))
+    /// }
+    /// ```
+    pub fn apply<T, S>(&self, mut rows: Vec<T>, compare: S) -> Vec<T>
+    where
+        T: Clone,
+        S: Fn(&T, &T, C) -> Ordering,
+    {
+        sort_by_columns(&mut rows, &self.sorting, compare);
+
+        get_rows_in_range(&rows, self.range.clone())
+    }
+
+    /// Same as [`apply`](TableQuery::apply) but compares the rows with [`compare_rows`](crate::compare_rows).
+    /// This requires [`TableRow`] to be implemented for the rows.
+    pub fn apply_rows<T>(&self, mut rows: Vec<T>) -> Vec<T>
+    where
+        T: TableRow<ColumnName = C> + Clone,
+    {
+        sort_rows(&mut rows, &self.sorting);
+
+        get_rows_in_range(&rows, self.range.clone())
+    }
+
+    /// Same as [`apply`](TableQuery::apply) but removes all rows for which `filter` returns `false`
+    /// before sorting.
+    pub fn apply_filtered<T, S, P>(&self, mut rows: Vec<T>, compare: S, filter: P) -> Vec<T>
+    where
+        T: Clone,
+        S: Fn(&T, &T, C) -> Ordering,
+        P: Fn(&T, &F) -> bool,
+    {
+        rows.retain(|row| filter(row, &self.filter));
+
+        self.apply(rows, compare)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TableColumn;
+
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    enum NumberColumn {
+        Value,
+    }
+
+    impl TableColumn for NumberColumn {
+        fn columns() -> Vec<Self> {
+            vec![NumberColumn::Value]
+        }
+
+        fn title(&self) -> String {
+            "Value".to_string()
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Number(i64);
+
+    impl TableRow for Number {
+        type ColumnName = NumberColumn;
+
+        fn cell_text(&self, _: NumberColumn) -> String {
+            self.0.to_string()
+        }
+
+        fn cell_value(&self, _: NumberColumn) -> crate::CellValue {
+            self.0.into()
+        }
+    }
+
+    fn query(range: Range<usize>) -> TableQuery<NumberColumn> {
+        TableQuery {
+            range,
+            sorting: VecDeque::from([(NumberColumn::Value, ColumnSort::Descending)]),
+            filter: (),
+        }
+    }
+
+    fn numbers(count: i64) -> Vec<Number> {
+        (0..count).map(Number).collect()
+    }
+
+    fn compare(a: &Number, b: &Number, _: NumberColumn) -> Ordering {
+        a.0.cmp(&b.0)
+    }
+
+    #[test]
+    fn apply_sorts_and_pages() {
+        let rows = query(1..3).apply(numbers(5), compare);
+
+        assert_eq!(rows, vec![Number(3), Number(2)]);
+    }
+
+    #[test]
+    fn apply_clamps_the_end_of_the_range() {
+        let rows = query(3..10).apply(numbers(5), compare);
+
+        assert_eq!(rows, vec![Number(1), Number(0)]);
+    }
+
+    #[test]
+    fn apply_returns_nothing_past_the_last_row() {
+        assert_eq!(query(10..20).apply(numbers(10), compare), vec![]);
+        assert_eq!(query(5..10).apply(numbers(5), compare), vec![]);
+    }
+
+    #[test]
+    fn apply_handles_empty_rows_and_ranges() {
+        assert_eq!(query(0..10).apply(vec![], compare), vec![]);
+        assert_eq!(query(2..2).apply(numbers(5), compare), vec![]);
+        assert_eq!(
+            query(Range { start: 3, end: 1 }).apply(numbers(5), compare),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn apply_rows_pages_like_apply() {
+        assert_eq!(
+            query(0..2).apply_rows(numbers(5)),
+            vec![Number(4), Number(3)]
+        );
+        assert_eq!(query(10..20).apply_rows(numbers(10)), vec![]);
+        assert_eq!(query(0..10).apply_rows(Vec::<Number>::new()), vec![]);
+    }
+
+    #[test]
+    fn apply_filtered_filters_before_paging() {
+        let rows = query(0..2).apply_filtered(numbers(6), compare, |row, _| row.0 % 2 == 0);
+
+        assert_eq!(rows, vec![Number(4), Number(2)]);
+    }
+}
@@ -0,0 +1,94 @@
+use crate::{CellValue, Collation, ColumnSort, NonePosition, SortOptions};
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+
+/// Information about the columns of a table. Implement this for the column name enum that is generated by
+/// deriving `TableComponent`.
+///
+/// This is used by features that work with the displayed columns outside of the rendered table
+/// like [exporting](crate::write_csv).
+pub trait TableColumn: Copy + 'static {
+    /// All columns that should be exported in the order they are displayed.
+    fn columns() -> Vec<Self>;
+
+    /// The title that is displayed in the header cell.
+    fn title(&self) -> String;
+
+    /// The number of digits after the decimal point.
+    fn precision(&self) -> Option<usize> {
+        None
+    }
+
+    /// The format string for dates and times.
+    fn format_string(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Whether the rows can be sorted by this column.
+    fn sortable(&self) -> bool {
+        true
+    }
+
+    /// The direction this column is sorted by when its header is clicked for the first time.
+    /// Pass it to [`toggle_column_sort_with`](crate::toggle_column_sort_with). Defaults to [`ColumnSort::Ascending`].
+    fn initial_sort(&self) -> ColumnSort {
+        ColumnSort::Ascending
+    }
+
+    /// The sorting the table starts with, for example to pass it to the `with_sorting` method of a data provider.
+    fn default_sorting() -> VecDeque<(Self, ColumnSort)> {
+        VecDeque::new()
+    }
+
+    /// How clicking the headers changes the sorting. Pass it to [`toggle_column_sort_with`](crate::toggle_column_sort_with).
+    fn sort_options() -> SortOptions {
+        SortOptions::default()
+    }
+
+    /// How texts are compared when sorting by this column. Defaults to comparing the bytes, see [`Collation`].
+    fn collation(&self) -> Collation {
+        Collation::default()
+    }
+
+    /// Where empty values are placed when sorting by this column. Defaults to [`NonePosition::First`].
+    fn none_position(&self) -> NonePosition {
+        NonePosition::First
+    }
+}
+
+/// Access to the displayed values of a row. Implement this for your struct to use the export functions.
+pub trait TableRow {
+    /// The column name enum that is generated by deriving `TableComponent`.
+    type ColumnName: TableColumn;
+
+    /// The value of the cell in `column` as it is displayed by the default cell renderers.
+    fn cell_text(&self, column: Self::ColumnName) -> String;
+
+    /// The typed value of the cell in `column`. Used by exports that keep the types like the XLSX export.
+    /// Numbers, booleans and dates become the respective [`CellValue`] variant. Defaults to the displayed text.
+    fn cell_value(&self, column: Self::ColumnName) -> CellValue {
+        CellValue::Text(self.cell_text(column))
+    }
+
+    /// Whether the cell in `column` has no value, for example because an `Option` field is `None`.
+    /// Such cells are placed according to [`TableColumn::none_position`] when sorting.
+    fn is_cell_none(&self, column: Self::ColumnName) -> bool {
+        matches!(self.cell_value(column), CellValue::Empty)
+    }
+
+    /// Compares the cells of two rows in `column` for sorting. By default the [`cell_value`](TableRow::cell_value)s
+    /// are compared with [`CellValue::compare_with`] using the [`collation`](TableColumn::collation) of the column.
+    /// Override this for values where this doesn't give the right order, like version strings.
+    fn compare_by(&self, other: &Self, column: Self::ColumnName) -> Ordering {
+        self.cell_value(column)
+            .compare_with(&other.cell_value(column), &column.collation())
+    }
+
+    /// Parses `text` with [`FromCellText`](crate::FromCellText) and sets it as the value of the field in `column`.
+    /// Returns an error message if the text can't be parsed or the column can't be edited. By default no column
+    /// can be edited.
+    #[allow(unused_variables)]
+    fn set_cell_text(&mut self, column: Self::ColumnName, text: &str) -> Result<(), String> {
+        Err(format!("{} is not editable", column.title()))
+    }
+}